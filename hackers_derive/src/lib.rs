@@ -4,6 +4,18 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::{parse_macro_input, DeriveInput, Data, Fields, Lit};
 
+/// Field attributes: `skip`, `name = "..."`, and `unsafe_deref`.
+///
+/// # Safety of `unsafe_deref`
+/// `#[fieldinfo(unsafe_deref)]` on a `*const T` / `*mut T` field (with
+/// `T: Copy + Debug`) copies the pointee out with `read_unaligned` every time
+/// `get_field_info` runs, which `StructViewer` does each frame. No reference
+/// is formed, so alignment and aliasing with a live `&mut T` don't matter,
+/// but the generated code still runs inside the safe `get_field_info`:
+/// adding the attribute is a promise that any non-null value points to
+/// `size_of::<T>()` readable, initialized bytes for as long as the struct is
+/// inspected. Anything else is undefined behavior. Null is shown as `null`.
+/// Only available with the `unsafe-deref` feature.
 #[proc_macro_derive(DeriveFieldInfo, attributes(fieldinfo))]
 pub fn field_info_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -25,12 +37,15 @@ pub fn field_info_derive(input: TokenStream) -> TokenStream {
 
         let mut field_name = ident.to_string();
         let mut skip = false;
+        let mut deref = false;
         
         for attr in &f.attrs {
             if attr.path().is_ident("fieldinfo") {
                 let _ = attr.parse_nested_meta(|meta| {
                     if meta.path.is_ident("skip") {
                         skip = true;
+                    } else if meta.path.is_ident("unsafe_deref") {
+                        deref = true;
                     } else if meta.path.is_ident("name") {
                         if let Ok(Lit::Str(s)) = meta.value()?.parse::<Lit>() {
                             field_name = s.value();
@@ -44,8 +59,31 @@ pub fn field_info_derive(input: TokenStream) -> TokenStream {
         if skip {
             None
         } else {
-            let type_str = quote!(#ty).to_string();
-            let interpret_expr = if type_str.starts_with("[c_char;") {
+            // quote! spaces tokens out (`[c_char ; 32]`), so compare without whitespace
+            let type_str = quote!(#ty).to_string().replace(' ', "");
+            let is_c_string = type_str.starts_with("[c_char;") || (type_str.starts_with('[') && type_str.contains("::c_char;"));
+            if deref && !cfg!(feature = "unsafe-deref") {
+                panic!("#[fieldinfo(unsafe_deref)] on `{}` requires the `unsafe-deref` feature", ident);
+            }
+            let interpret_expr = if deref {
+                if !matches!(ty, syn::Type::Ptr(_)) {
+                    panic!("#[fieldinfo(unsafe_deref)] on `{}` requires a raw pointer field", ident);
+                }
+                quote! {
+                    Some({
+                        fn require_copy<T: Copy>(ptr: *const T) -> *const T { ptr }
+                        let ptr = require_copy(self.#ident as *const _);
+                        if ptr.is_null() {
+                            "null".to_string()
+                        } else {
+                            // SAFETY: caller contract of #[fieldinfo(unsafe_deref)], see DeriveFieldInfo docs;
+                            // a by-value unaligned copy avoids alignment and aliasing requirements
+                            let value = unsafe { ptr.read_unaligned() };
+                            format!("{:p} -> {:?}", ptr, value)
+                        }
+                    })
+                }
+            } else if is_c_string {
                 quote! {
                     Some({
                         let raw_bytes: &[u8] = unsafe {
@@ -63,18 +101,33 @@ pub fn field_info_derive(input: TokenStream) -> TokenStream {
                         )
                     })
                 }
+            } else if let syn::Type::Array(array) = ty {
+                // One entry per element, named `field.i` so StructViewer groups them
+                let elem = &array.elem;
+                let len = &array.len;
+                return Some(quote! {
+                    for i in 0..(#len) {
+                        fields.push(hackers::FieldMeta {
+                            name: format!("{}.{}", #field_name, i),
+                            offset: memoffset::offset_of!(#name, #ident) + i * std::mem::size_of::<#elem>(),
+                            size: std::mem::size_of::<#elem>(),
+                            type_name: stringify!(#elem),
+                            interpret: Some(format!("{:?}", self.#ident[i])),
+                        });
+                    }
+                });
             } else {
                 quote! { Some(format!("{:?}", self.#ident)) }
             };
             
             Some(quote! {
-                hackers::FieldMeta {
+                fields.push(hackers::FieldMeta {
                     name: #field_name.into(),
                     offset: memoffset::offset_of!(#name, #ident),
                     size: std::mem::size_of::<#ty>(),
                     type_name: stringify!(#ty),
                     interpret: #interpret_expr,
-                }
+                });
             })
         }
     });
@@ -82,12 +135,132 @@ pub fn field_info_derive(input: TokenStream) -> TokenStream {
     let expanded = quote! {
         impl hackers::FieldInfo for #name {
             fn get_field_info(&self) -> Vec<hackers::FieldMeta> {
-                vec![
-                    #(#field_infos),*
-                ]
+                let mut fields = Vec::new();
+                #(#field_infos)*
+                fields
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Generates `hackers::gui::ConfigUi` with one imgui widget per field.
+///
+/// Field attributes:
+/// - `#[config(skip)]` - leave the field out of the panel
+/// - `#[config(label = "...")]` - widget label (defaults to the field name)
+/// - `#[config(range(min, max))]` - slider instead of an input box (numeric fields)
+/// - `#[config(color)]` - color editor for `[f32; 3]` / `[f32; 4]`
+/// - `#[config(combo)]` - dropdown for enums implementing `hackers::gui::IntoEnumIterator`
+/// - `#[config(nested)]` - tree node rendering a field that is itself `ConfigUi`
+#[proc_macro_derive(ConfigUi, attributes(config))]
+pub fn config_ui_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = if let Data::Struct(data_struct) = &input.data {
+        if let Fields::Named(named_fields) = &data_struct.fields {
+            &named_fields.named
+        } else {
+            panic!("ConfigUi can only be derived for named fields.");
+        }
+    } else {
+        panic!("ConfigUi can only be derived for structs.");
+    };
+
+    let widgets = fields.iter().filter_map(|f| {
+        let ident = f.ident.as_ref().unwrap();
+        let ty = &f.ty;
+
+        let mut label = ident.to_string();
+        let mut skip = false;
+        let mut range: Option<(syn::Expr, syn::Expr)> = None;
+        let mut color = false;
+        let mut combo = false;
+        let mut nested = false;
+
+        for attr in &f.attrs {
+            if attr.path().is_ident("config") {
+                let _ = attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("skip") {
+                        skip = true;
+                    } else if meta.path.is_ident("label") {
+                        if let Ok(Lit::Str(s)) = meta.value()?.parse::<Lit>() {
+                            label = s.value();
+                        }
+                    } else if meta.path.is_ident("range") {
+                        let content;
+                        syn::parenthesized!(content in meta.input);
+                        let min: syn::Expr = content.parse()?;
+                        content.parse::<syn::Token![,]>()?;
+                        let max: syn::Expr = content.parse()?;
+                        range = Some((min, max));
+                    } else if meta.path.is_ident("color") {
+                        color = true;
+                    } else if meta.path.is_ident("combo") {
+                        combo = true;
+                    } else if meta.path.is_ident("nested") {
+                        nested = true;
+                    }
+                    Ok(())
+                });
+            }
+        }
+
+        if skip {
+            return None;
+        }
+
+        // Field ident after "##" keeps ids unique when labels repeat
+        let widget_label = format!("{}##{}", label, ident);
+        let type_str = quote!(#ty).to_string().replace(' ', "");
+
+        let widget = if nested {
+            quote! {
+                if let Some(_node) = ui.tree_node(#widget_label) {
+                    changed |= hackers::gui::ConfigUi::render_config(&mut self.#ident, ui);
+                }
+            }
+        } else if combo {
+            quote! { changed |= hackers::gui::enum_dropdown(ui, #widget_label, &mut self.#ident); }
+        } else if color {
+            match type_str.as_str() {
+                "[f32;3]" => quote! { changed |= ui.color_edit3(#widget_label, &mut self.#ident); },
+                "[f32;4]" => quote! { changed |= ui.color_edit4(#widget_label, &mut self.#ident); },
+                _ => panic!("ConfigUi: #[config(color)] on `{}` needs [f32; 3] or [f32; 4].", ident),
+            }
+        } else {
+            match type_str.as_str() {
+                "bool" => quote! { changed |= ui.checkbox(#widget_label, &mut self.#ident); },
+                "String" => quote! { changed |= ui.input_text(#widget_label, &mut self.#ident).build(); },
+                "f32" | "f64" | "i8" | "u8" | "i16" | "u16" | "i32" | "u32" | "i64" | "u64" => {
+                    if let Some((min, max)) = &range {
+                        quote! { changed |= ui.slider(#widget_label, #min, #max, &mut self.#ident); }
+                    } else {
+                        quote! { changed |= ui.input_scalar(#widget_label, &mut self.#ident).build(); }
+                    }
+                }
+                _ => panic!(
+                    "ConfigUi: no widget for field `{}` of type `{}`; use #[config(combo)], #[config(nested)] or #[config(skip)].",
+                    ident, type_str
+                ),
+            }
+        };
+
+        Some(widget)
+    });
+
+    let expanded = quote! {
+        impl #impl_generics hackers::gui::ConfigUi for #name #ty_generics #where_clause {
+            fn render_config(&mut self, ui: &hackers::imgui::Ui) -> bool {
+                let mut changed = false;
+                #(#widgets)*
+                changed
             }
         }
     };
 
     TokenStream::from(expanded)
-}
\ No newline at end of file
+}
@@ -0,0 +1,144 @@
+/// World <-> screen transform for overlays and 2D views.
+///
+/// `offset` is the world position shown at the top-left corner of the
+/// viewport; `viewport` is the screen-space rect `(min, size)` being drawn
+/// into, e.g. `(ui.window_pos(), ui.window_size())` or the whole display.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Camera2D {
+    pub zoom: f32,
+    pub offset: [f32; 2],
+    pub viewport: ([f32; 2], [f32; 2]),
+}
+
+impl Default for Camera2D {
+    fn default() -> Self {
+        Self {
+            zoom: 1.0,
+            offset: [0.0, 0.0],
+            viewport: ([0.0, 0.0], [0.0, 0.0]),
+        }
+    }
+}
+
+impl Camera2D {
+    pub fn new(zoom: f32, offset: [f32; 2], viewport_min: [f32; 2], viewport_size: [f32; 2]) -> Self {
+        Self {
+            zoom,
+            offset,
+            viewport: (viewport_min, viewport_size),
+        }
+    }
+
+    /// Camera covering the whole display at 1:1
+    pub fn for_display(ui: &imgui::Ui) -> Self {
+        Self {
+            viewport: ([0.0, 0.0], ui.io().display_size),
+            ..Self::default()
+        }
+    }
+
+    pub fn world_to_screen(&self, world: [f32; 2]) -> [f32; 2] {
+        let (min, _) = self.viewport;
+        [
+            min[0] + (world[0] - self.offset[0]) * self.zoom,
+            min[1] + (world[1] - self.offset[1]) * self.zoom,
+        ]
+    }
+
+    pub fn screen_to_world(&self, screen: [f32; 2]) -> [f32; 2] {
+        let (min, _) = self.viewport;
+        let zoom = if self.zoom == 0.0 { 1.0 } else { self.zoom };
+        [
+            self.offset[0] + (screen[0] - min[0]) / zoom,
+            self.offset[1] + (screen[1] - min[1]) / zoom,
+        ]
+    }
+
+    /// World-space length to screen pixels
+    pub fn scale(&self, world_len: f32) -> f32 {
+        world_len * self.zoom
+    }
+
+    /// Move the camera so `world` sits at the viewport center
+    pub fn center_on(&mut self, world: [f32; 2]) {
+        let (_, size) = self.viewport;
+        let zoom = if self.zoom == 0.0 { 1.0 } else { self.zoom };
+        self.offset = [
+            world[0] - size[0] / (2.0 * zoom),
+            world[1] - size[1] / (2.0 * zoom),
+        ];
+    }
+
+    /// Grid cell under a screen position, for tiles of `tile_size` world units
+    /// starting at world origin. Pass `ui.io().mouse_pos` to pick under the cursor.
+    pub fn pick_tile(&self, screen: [f32; 2], tile_size: [f32; 2]) -> [i32; 2] {
+        let world = self.screen_to_world(screen);
+        [
+            (world[0] / tile_size[0]).floor() as i32,
+            (world[1] / tile_size[1]).floor() as i32,
+        ]
+    }
+
+    /// Tile under the mouse cursor, or `None` if the cursor is outside the viewport
+    pub fn pick_tile_under_mouse(&self, ui: &imgui::Ui, tile_size: [f32; 2]) -> Option<[i32; 2]> {
+        let mouse = ui.io().mouse_pos;
+        self.contains_screen(mouse).then(|| self.pick_tile(mouse, tile_size))
+    }
+
+    /// Whether a screen-space point falls inside the viewport
+    pub fn contains_screen(&self, screen: [f32; 2]) -> bool {
+        let (min, size) = self.viewport;
+        screen[0] >= min[0]
+            && screen[1] >= min[1]
+            && screen[0] <= min[0] + size[0]
+            && screen[1] <= min[1] + size[1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: [f32; 2], b: [f32; 2]) {
+        assert!((a[0] - b[0]).abs() < 1e-4 && (a[1] - b[1]).abs() < 1e-4, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn world_screen_roundtrip() {
+        let camera = Camera2D::new(2.0, [10.0, -5.0], [100.0, 50.0], [800.0, 600.0]);
+        assert_close(camera.world_to_screen([10.0, -5.0]), [100.0, 50.0]);
+        assert_close(camera.world_to_screen([15.0, 0.0]), [110.0, 60.0]);
+
+        let world = [123.5, -42.25];
+        assert_close(camera.screen_to_world(camera.world_to_screen(world)), world);
+    }
+
+    #[test]
+    fn center_on_puts_point_mid_viewport() {
+        let mut camera = Camera2D::new(4.0, [0.0, 0.0], [0.0, 0.0], [400.0, 200.0]);
+        camera.center_on([50.0, 50.0]);
+        assert_close(camera.world_to_screen([50.0, 50.0]), [200.0, 100.0]);
+    }
+
+    #[test]
+    fn zero_zoom_does_not_divide_by_zero() {
+        let camera = Camera2D::new(0.0, [1.0, 2.0], [0.0, 0.0], [10.0, 10.0]);
+        assert_close(camera.screen_to_world([3.0, 4.0]), [4.0, 6.0]);
+    }
+
+    #[test]
+    fn pick_tile_floors_negative_coordinates() {
+        let camera = Camera2D::default();
+        assert_eq!(camera.pick_tile([15.0, 31.9], [16.0, 16.0]), [0, 1]);
+        assert_eq!(camera.pick_tile([-0.5, -16.0], [16.0, 16.0]), [-1, -1]);
+    }
+
+    #[test]
+    fn contains_screen_is_inclusive() {
+        let camera = Camera2D::new(1.0, [0.0, 0.0], [10.0, 10.0], [20.0, 20.0]);
+        assert!(camera.contains_screen([10.0, 10.0]));
+        assert!(camera.contains_screen([30.0, 30.0]));
+        assert!(!camera.contains_screen([9.9, 15.0]));
+        assert!(!camera.contains_screen([15.0, 30.1]));
+    }
+}
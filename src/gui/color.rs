@@ -95,4 +95,121 @@ impl HaCKS {
         [r + 0.3, g + 0.3, b + 0.3, 1.0]
     }
 
-}
\ No newline at end of file
+}
+
+// Packed colors use imgui's IM_COL32 layout: 0xAABBGGRR, i.e. R in the low byte.
+
+/// Pack 8-bit channels into imgui's `u32` color layout
+pub const fn pack_rgba(r: u8, g: u8, b: u8, a: u8) -> u32 {
+    ((a as u32) << 24) | ((b as u32) << 16) | ((g as u32) << 8) | (r as u32)
+}
+
+/// Split an imgui `u32` color into `[r, g, b, a]` bytes
+pub const fn unpack_rgba(packed: u32) -> [u8; 4] {
+    [
+        (packed & 0xFF) as u8,
+        ((packed >> 8) & 0xFF) as u8,
+        ((packed >> 16) & 0xFF) as u8,
+        ((packed >> 24) & 0xFF) as u8,
+    ]
+}
+
+/// `[f32; 4]` RGBA (0..1, clamped) to an imgui `u32` color
+pub fn color_to_u32(color: [f32; 4]) -> u32 {
+    let c = |v: f32| (v.clamp(0.0, 1.0) * 255.0 + 0.5) as u8;
+    pack_rgba(c(color[0]), c(color[1]), c(color[2]), c(color[3]))
+}
+
+/// imgui `u32` color to `[f32; 4]` RGBA (0..1)
+pub fn u32_to_color(packed: u32) -> [f32; 4] {
+    let [r, g, b, a] = unpack_rgba(packed);
+    [r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, a as f32 / 255.0]
+}
+
+/// RGB (0..1) to HSV with hue, saturation and value all in 0..1
+pub fn rgb_to_hsv(rgb: [f32; 3]) -> [f32; 3] {
+    let [r, g, b] = rgb;
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let sector = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    };
+    let h = sector / 6.0;
+    let s = if max == 0.0 { 0.0 } else { delta / max };
+
+    [h, s, max]
+}
+
+/// HSV (all 0..1) to RGB (0..1); hue wraps
+pub fn hsv_to_rgb(hsv: [f32; 3]) -> [f32; 3] {
+    let [h, s, v] = hsv;
+    let h = h.rem_euclid(1.0) * 6.0;
+    let c = v * s;
+    let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+    let m = v - c;
+
+    let (r, g, b) = match h as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    [r + m, g + m, b + m]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: [f32; 3], b: [f32; 3]) {
+        for i in 0..3 {
+            assert!((a[i] - b[i]).abs() < 1e-4, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn primaries_convert_both_ways() {
+        let cases = [
+            ([1.0, 0.0, 0.0], [0.0, 1.0, 1.0]),
+            ([0.0, 1.0, 0.0], [1.0 / 3.0, 1.0, 1.0]),
+            ([0.0, 0.0, 1.0], [2.0 / 3.0, 1.0, 1.0]),
+            ([1.0, 1.0, 1.0], [0.0, 0.0, 1.0]),
+            ([0.0, 0.0, 0.0], [0.0, 0.0, 0.0]),
+        ];
+        for (rgb, hsv) in cases {
+            assert_close(rgb_to_hsv(rgb), hsv);
+            assert_close(hsv_to_rgb(hsv), rgb);
+        }
+    }
+
+    #[test]
+    fn hsv_roundtrip() {
+        for rgb in [[0.2, 0.4, 0.6], [0.9, 0.1, 0.5], [0.5, 0.5, 0.1], [1.0, 0.0, 0.999]] {
+            assert_close(hsv_to_rgb(rgb_to_hsv(rgb)), rgb);
+        }
+    }
+
+    #[test]
+    fn hue_wraps() {
+        assert_close(hsv_to_rgb([1.0, 1.0, 1.0]), [1.0, 0.0, 0.0]);
+        assert_close(hsv_to_rgb([-1.0 / 3.0, 1.0, 1.0]), [0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn packed_colors_roundtrip() {
+        assert_eq!(pack_rgba(0x11, 0x22, 0x33, 0x44), 0x4433_2211);
+        assert_eq!(unpack_rgba(0x4433_2211), [0x11, 0x22, 0x33, 0x44]);
+        assert_eq!(color_to_u32(u32_to_color(0x80FF_4020)), 0x80FF_4020);
+        assert_eq!(color_to_u32([2.0, -1.0, 0.0, 1.0]), pack_rgba(255, 0, 0, 255));
+    }
+}
@@ -2,7 +2,64 @@ use std::any::TypeId;
 
 use imgui::{Condition, TreeNodeFlags, Ui};
 
-use crate::HaCKS;
+use crate::{HaCKCapabilities, HaCKS, HaCMetadata, Phase};
+
+/// Read-only view of everything a module declares about itself.
+pub fn render_module_details(ui: &Ui, metadata: &HaCMetadata, capabilities: HaCKCapabilities) {
+    ui.text(format!("Name: {}", metadata.name));
+    ui.text(format!("Description: {}", metadata.description));
+    ui.text(format!("Category: {}", metadata.category));
+    ui.text(format!("Hotkey: {:?}", metadata.hotkeys));
+
+    ui.separator();
+
+    ui.text(format!("Menu Weight: {}", metadata.menu_weight));
+    ui.text(format!("Window Weight: {}", metadata.window_weight));
+    ui.text(format!("Draw Weight: {}", metadata.draw_weight));
+    ui.text(format!("Update Weight: {}", metadata.update_weight));
+    ui.text(format!("Menu Pinned: {}", metadata.menu_pinned));
+
+    ui.separator();
+
+    ui.text(format!("Visible in GUI: {}", metadata.visible_in_gui));
+    ui.text(format!("Menu Enabled: {}", metadata.is_menu_enabled));
+    ui.text(format!("Window Enabled: {}", metadata.is_window_enabled));
+    ui.text(format!("Render Enabled: {}", metadata.is_render_enabled));
+    ui.text(format!("Update Enabled: {}", metadata.is_update_enabled));
+    match metadata.update_interval_ms {
+        Some(ms) => ui.text(format!("Update Interval: {} ms", ms)),
+        None => ui.text("Update Interval: every frame"),
+    }
+    ui.text(format!(
+        "Draw Layers: {}{}",
+        if metadata.draws_background { "background " } else { "" },
+        if metadata.draws_foreground { "foreground" } else { "" },
+    ));
+
+    ui.separator();
+
+    ui.text(format!("Window Pos: {:?}", metadata.window_pos));
+    ui.text(format!("Window Size: {:?}", metadata.window_size));
+    ui.text(format!("Auto Resize: {}", metadata.auto_resize_window));
+    ui.text(format!("Access: {:?}", metadata.access_control));
+
+    ui.separator();
+
+    let declared: Vec<&str> = [
+        (HaCKCapabilities::FRAME_HOOKS, "frame hooks"),
+        (HaCKCapabilities::HOTKEYS, "hotkeys"),
+        (HaCKCapabilities::DRAW, "draw"),
+    ]
+    .into_iter()
+    .filter(|(flag, _)| capabilities.contains(*flag))
+    .map(|(_, name)| name)
+    .collect();
+    if declared.is_empty() {
+        ui.text("Capabilities: none declared");
+    } else {
+        ui.text(format!("Capabilities: {}", declared.join(", ")));
+    }
+}
 
 impl HaCKS {
     pub fn render_debug_window(&mut self, ui: &Ui) {
@@ -10,7 +67,7 @@ impl HaCKS {
             return;
         }
     
-        let scale = ui.current_font_size() / 14.0;
+        let scale = crate::gui::ui_scale(ui);
     
         ui.window("Module Debug Viewer")
             .opened(&mut self.show_debug_window.borrow_mut())
@@ -41,36 +98,14 @@ impl HaCKS {
     
                         // --- Metadata tree ---
                         if let Some(_t) = ui.tree_node("Metadata") {
-                            let metadata = module.metadata();
-    
-                            ui.text(format!("Name: {}", metadata.name));
-                            ui.text(format!("Description: {}", metadata.description));
-                            ui.text(format!("Category: {}", metadata.category));
-                            ui.text(format!("Hotkey: {:?}", metadata.hotkeys));
-    
-                            ui.separator();
-    
-                            ui.text(format!("Menu Weight: {}", metadata.menu_weight));
-                            ui.text(format!("Window Weight: {}", metadata.window_weight));
-                            ui.text(format!("Draw Weight: {}", metadata.draw_weight));
-                            ui.text(format!("Update Weight: {}", metadata.update_weight));
-    
-                            ui.separator();
-    
-                            ui.text(format!("Visible in GUI: {}", metadata.visible_in_gui));
-                            ui.text(format!("Menu Enabled: {}", metadata.is_menu_enabled));
-                            ui.text(format!("Window Enabled: {}", metadata.is_window_enabled));
-                            ui.text(format!("Render Enabled: {}", metadata.is_render_enabled));
-                            ui.text(format!("Update Enabled: {}", metadata.is_update_enabled));
-    
-                            ui.text(format!("Window Pos: {:?}", metadata.window_pos));
-                            ui.text(format!("Window Size: {:?}", metadata.window_size));
+                            render_module_details(ui, module.metadata(), module.capabilities());
                         }
     
                         // --- JSON tree ---
                         if let Some(_t) = ui.tree_node("Module Data (JSON)") {
-                            match module.to_json_data_only() {
-                                Ok(json) => {
+                            match self.guard(&type_id, "to_json_data_only", || module.to_json_data_only()) {
+                                None => ui.text_disabled("(module disabled)"),
+                                Some(Ok(json)) => {
                                     let json_pretty = serde_json::to_string_pretty(&json)
                                         .unwrap_or_else(|_| "Failed to format JSON".to_string());
     
@@ -80,7 +115,7 @@ impl HaCKS {
                                             ui.text_wrapped(&json_pretty);
                                         });
                                 }
-                                Err(e) => {
+                                Some(Err(e)) => {
                                     ui.text_colored(
                                         [1.0, 0.0, 0.0, 1.0],
                                         format!("Serialization error: {}", e),
@@ -89,6 +124,55 @@ impl HaCKS {
                             }
                         }
     
+                        // --- Live state tree ---
+                        let state = self
+                            .guard(&type_id, "describe_state", || module.describe_state())
+                            .unwrap_or_default();
+                        if !state.is_null() {
+                            if let Some(_t) = ui.tree_node("Live State") {
+                                let state_pretty = serde_json::to_string_pretty(&state)
+                                    .unwrap_or_else(|_| "Failed to format JSON".to_string());
+                                ui.text_wrapped(&state_pretty);
+                            }
+                        }
+
+                        // --- Field layout tree ---
+                        let fields = self
+                            .guard(&type_id, "debug_fields", || module.debug_fields())
+                            .unwrap_or_default();
+                        if !fields.is_empty() {
+                            if let Some(_t) = ui.tree_node("Fields") {
+                                if let Some(_table) = ui.begin_table_with_flags(
+                                    "##debug_fields",
+                                    5,
+                                    imgui::TableFlags::BORDERS
+                                        | imgui::TableFlags::ROW_BG
+                                        | imgui::TableFlags::SIZING_FIXED_FIT,
+                                ) {
+                                    ui.table_setup_column("Name");
+                                    ui.table_setup_column("Offset");
+                                    ui.table_setup_column("Size");
+                                    ui.table_setup_column("Type");
+                                    ui.table_setup_column("Value");
+                                    ui.table_headers_row();
+
+                                    for field in &fields {
+                                        ui.table_next_row();
+                                        ui.table_set_column_index(0);
+                                        ui.text(&field.name);
+                                        ui.table_set_column_index(1);
+                                        ui.text(format!("{:#06X}", field.offset));
+                                        ui.table_set_column_index(2);
+                                        ui.text(format!("{}", field.size));
+                                        ui.table_set_column_index(3);
+                                        ui.text(field.type_name);
+                                        ui.table_set_column_index(4);
+                                        ui.text(field.interpret.as_deref().unwrap_or("-"));
+                                    }
+                                }
+                            }
+                        }
+    
                         ui.text_colored([0.6, 0.6, 0.6, 1.0], format!("TypeId: {:?}", type_id));
     
                         ui.unindent();
@@ -100,7 +184,7 @@ impl HaCKS {
     
     
     pub fn render_metadata_editor_windows(&mut self, ui: &imgui::Ui) {
-        let scale = ui.current_font_size()/14.0;
+        let scale = crate::gui::ui_scale(ui);
         // Visualization window
         let mut show_viz = *self.metadata_window_viz.borrow();
         if show_viz {
@@ -305,6 +389,43 @@ impl HaCKS {
                     ui.next_column();
         
                     ui.columns(1, "", false);
+
+                    // Health: failures caught by the host, with a way back in
+                    let health = self.module_health(type_id);
+                    let now = std::time::Instant::now();
+                    let color = if health.is_ok() {
+                        [0.5, 1.0, 0.5, 1.0]
+                    } else if health.is_disabled(now) {
+                        [1.0, 0.4, 0.4, 1.0]
+                    } else {
+                        [1.0, 1.0, 0.5, 1.0]
+                    };
+                    ui.text("Health:");
+                    ui.same_line();
+                    ui.text_colored(color, health.status_text(now));
+                    if !health.is_ok() {
+                        ui.same_line();
+                        if ui.small_button("Re-enable") {
+                            self.reenable_module(type_id);
+                        }
+                        if let Some(error) = &health.last_error {
+                            ui.text_disabled(format!("Last error: {}", error));
+                        }
+                    }
+
+                    // Host overrides: silence a phase without touching the module's own flags
+                    ui.text("Force off:");
+                    for phase in Phase::ALL {
+                        ui.same_line();
+                        let mut forced_off = self.phase_override(name, phase) == Some(false);
+                        if ui.checkbox(format!("{:?}##force_off", phase), &mut forced_off) {
+                            if forced_off {
+                                self.set_phase_enabled(name, phase, false);
+                            } else {
+                                self.clear_phase_override(name, phase);
+                            }
+                        }
+                    }
         
                     // Hotkeys
                     if ui.collapsing_header("Hotkeys", imgui::TreeNodeFlags::empty()) {
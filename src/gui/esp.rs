@@ -0,0 +1,125 @@
+use imgui::DrawListMut;
+
+use crate::gui::Camera2D;
+
+/// One world-space entity to box and label.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EspEntity {
+    /// Center of the box in world units
+    pub world_pos: [f32; 2],
+    pub label: String,
+    pub color: [f32; 4],
+    /// Box width/height in world units
+    pub box_size: [f32; 2],
+}
+
+/// Screen-space boxes and labels over world entities, for `render_draw`.
+///
+/// ```ignore
+/// let esp = Esp::new(Camera2D::for_display(ui));
+/// esp.render(draw_fg, &entities);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Esp {
+    pub camera: Camera2D,
+    pub box_thickness: f32,
+    /// Draw a dark outline behind labels so they stay readable on any background
+    pub outline_labels: bool,
+}
+
+impl Esp {
+    pub fn new(camera: Camera2D) -> Self {
+        Self {
+            camera,
+            box_thickness: 1.5,
+            outline_labels: true,
+        }
+    }
+
+    /// Screen rect `(min, max)` of an entity's box
+    pub fn project(&self, entity: &EspEntity) -> ([f32; 2], [f32; 2]) {
+        let center = self.camera.world_to_screen(entity.world_pos);
+        let half = [
+            self.camera.scale(entity.box_size[0]) * 0.5,
+            self.camera.scale(entity.box_size[1]) * 0.5,
+        ];
+        (
+            [center[0] - half[0], center[1] - half[1]],
+            [center[0] + half[0], center[1] + half[1]],
+        )
+    }
+
+    /// Whether any part of the entity's box overlaps the camera viewport
+    pub fn is_visible(&self, entity: &EspEntity) -> bool {
+        let (min, max) = self.project(entity);
+        let (view_min, view_size) = self.camera.viewport;
+        max[0] >= view_min[0]
+            && max[1] >= view_min[1]
+            && min[0] <= view_min[0] + view_size[0]
+            && min[1] <= view_min[1] + view_size[1]
+    }
+
+    /// Draw every visible entity; returns how many were drawn.
+    pub fn render(&self, draw_list: &DrawListMut, entities: &[EspEntity]) -> usize {
+        let mut drawn = 0;
+        for entity in entities.iter().filter(|e| self.is_visible(e)) {
+            let (min, max) = self.project(entity);
+            draw_list
+                .add_rect(min, max, entity.color)
+                .thickness(self.box_thickness)
+                .build();
+
+            if !entity.label.is_empty() {
+                let pos = [min[0], max[1] + 2.0];
+                if self.outline_labels {
+                    let shadow = [0.0, 0.0, 0.0, entity.color[3]];
+                    for (dx, dy) in [(-1.0, 0.0), (1.0, 0.0), (0.0, -1.0), (0.0, 1.0)] {
+                        draw_list.add_text([pos[0] + dx, pos[1] + dy], shadow, &entity.label);
+                    }
+                }
+                draw_list.add_text(pos, entity.color, &entity.label);
+            }
+            drawn += 1;
+        }
+        drawn
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entity(world_pos: [f32; 2], box_size: [f32; 2]) -> EspEntity {
+        EspEntity {
+            world_pos,
+            label: String::new(),
+            color: [1.0, 0.0, 0.0, 1.0],
+            box_size,
+        }
+    }
+
+    #[test]
+    fn project_centers_scaled_box_on_screen_position() {
+        let esp = Esp::new(Camera2D::new(2.0, [10.0, 20.0], [100.0, 50.0], [800.0, 600.0]));
+        // (30, 40) is 20 units past the offset on both axes -> 40px at 2x zoom
+        let (min, max) = esp.project(&entity([30.0, 40.0], [10.0, 4.0]));
+        assert_eq!(min, [130.0, 86.0]);
+        assert_eq!(max, [150.0, 94.0]);
+    }
+
+    #[test]
+    fn is_visible_culls_boxes_outside_the_viewport() {
+        let esp = Esp::new(Camera2D::new(1.0, [0.0, 0.0], [0.0, 0.0], [640.0, 480.0]));
+        assert!(esp.is_visible(&entity([320.0, 240.0], [10.0, 10.0])));
+        // Straddling an edge still counts
+        assert!(esp.is_visible(&entity([-4.0, 240.0], [10.0, 10.0])));
+
+        // Past the right/bottom edges
+        assert!(!esp.is_visible(&entity([700.0, 240.0], [10.0, 10.0])));
+        assert!(!esp.is_visible(&entity([320.0, 500.0], [10.0, 10.0])));
+        // Behind the camera: a 2D camera has no depth, so that means before
+        // its offset, off the left/top edges
+        assert!(!esp.is_visible(&entity([-20.0, 240.0], [10.0, 10.0])));
+        assert!(!esp.is_visible(&entity([320.0, -20.0], [10.0, 10.0])));
+    }
+}
@@ -4,7 +4,7 @@ use imgui::{Ui, Key};
 use crate::metadata::HotkeyBinding;
 use std::any::TypeId;
 
-use crate::{HaCKS, HaCMetadata};
+use crate::{HaCKCapabilities, HaCKS, HaCMetadata};
 
 impl HaCKS {
     /// Sync all module hotkeys to the manager (call on init/module load)
@@ -21,7 +21,8 @@ impl HaCKS {
         }
     }
     
-    /// Dispatch triggered hotkeys to modules (call in render_draw)
+    /// Dispatch triggered hotkeys to modules (call in render_draw); modules
+    /// not declaring `HOTKEYS`, or disabled for failing, are skipped
     pub fn dispatch_hotkeys(&mut self, ui: &imgui::Ui) {
         let triggered = self.hotkey_manager.borrow_mut().poll_all(ui);
     
@@ -32,7 +33,10 @@ impl HaCKS {
                 for tid in self.hacs.keys() {
                     if format!("{:?}", tid) == type_hash {
                         if let Some(module_rc) = self.hacs.get(tid) {
-                            module_rc.borrow_mut().on_hotkey(hotkey_id);
+                            if !module_rc.borrow().capabilities().contains(HaCKCapabilities::HOTKEYS) {
+                                break;
+                            }
+                            self.guard(tid, "on_hotkey", || module_rc.borrow_mut().on_hotkey(hotkey_id));
                         }
                         break;
                     }
@@ -131,6 +135,22 @@ impl Hotkey {
         
         key_down && shift_match && ctrl_match && alt_match
     }
+
+    fn modifiers_match(&self, ui: &Ui) -> bool {
+        let io = ui.io();
+        (!self.shift || io.key_shift) && (!self.ctrl || io.key_ctrl) && (!self.alt || io.key_alt)
+    }
+
+    /// True only on the frame the key goes down (ignores key repeat)
+    pub fn is_just_pressed(&self, ui: &Ui) -> bool {
+        ui.is_key_pressed_no_repeat(self.key) && self.modifiers_match(ui)
+    }
+
+    /// True only on the frame the key is let go (modifiers are not checked,
+    /// they're often released first)
+    pub fn is_released(&self, ui: &Ui) -> bool {
+        ui.is_key_released(self.key)
+    }
 }
 
 /// State tracking for a registered hotkey
@@ -194,6 +214,8 @@ impl HotkeyState {
 pub struct HotkeyManager {
     hotkeys: HashMap<String, HotkeyState>,
     capture_state: Option<String>,  // Add this field
+    /// Suppress triggers while an imgui text field has keyboard focus
+    block_while_typing: bool,
 }
 
 impl Default for HotkeyManager {
@@ -207,8 +229,20 @@ impl HotkeyManager {
         Self {
             hotkeys: HashMap::new(),
             capture_state: None,
+            block_while_typing: true,
         }
     }
+
+    /// Whether imgui is currently consuming keystrokes for a text field.
+    /// Modules polling keys themselves should skip game input while this is true.
+    pub fn text_input_active(ui: &Ui) -> bool {
+        ui.io().want_text_input
+    }
+
+    /// Enable/disable suppressing hotkeys while typing (on by default)
+    pub fn set_block_while_typing(&mut self, block: bool) {
+        self.block_while_typing = block;
+    }
     
     /// Create a new HotkeyManager with a loader function
     /// 
@@ -308,6 +342,13 @@ impl HotkeyManager {
         let id_str = id.to_string();
         if let Some(state) = self.hotkeys.get_mut(&id_str) {
             let is_pressed = state.hotkey.is_pressed(ui);
+
+            // Keep edge state current so releasing the key in a text box
+            // doesn't fire once focus leaves it
+            if self.block_while_typing && Self::text_input_active(ui) {
+                state.was_pressed = is_pressed;
+                return false;
+            }
             
             // Edge detection: only trigger on press edge (not held)
             if is_pressed && !state.was_pressed && state.can_trigger() {
@@ -333,6 +374,17 @@ impl HotkeyManager {
         }
     }
     
+    /// Check if a hotkey's key was released this frame (no cooldown check)
+    pub fn is_released<I>(&self, id: I, ui: &Ui) -> bool 
+    where
+        I: ToString
+    {
+        self.hotkeys
+            .get(&id.to_string())
+            .map(|state| state.hotkey.is_released(ui))
+            .unwrap_or(false)
+    }
+    
     /// Force reset the cooldown for a hotkey (makes it immediately triggerable)
     pub fn reset_cooldown<I>(&mut self, id: I) 
     where
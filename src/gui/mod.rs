@@ -3,5 +3,9 @@ pub mod render;
 pub mod color;
 pub mod hotkey_manager;
 pub mod widgets;
+pub mod camera;
+pub mod esp;
 
-pub use widgets::*;
\ No newline at end of file
+pub use widgets::*;
+pub use camera::Camera2D;
+pub use esp::{Esp, EspEntity};
\ No newline at end of file
@@ -1,6 +1,6 @@
 use std::{any::{TypeId}, collections::{BTreeMap, HashMap}};
 use imgui::{Condition, Ui, DrawListMut};
-use crate::HaCKS;
+use crate::{gui::Camera2D, HaCKCapabilities, HaCKS, Phase};
 
 impl HaCKS {
 
@@ -21,7 +21,7 @@ impl HaCKS {
   
     fn render_group_window(&mut self, ui: &imgui::Ui, name: &str, entries: &[(Vec<String>, TypeId)], path: &[String]) {
         let mut show = true;
-        let scale = ui.current_font_size()/14.0;
+        let scale = crate::gui::ui_scale(ui);
         ui.window(name)
             .opened(&mut show)
             .always_auto_resize(true)
@@ -60,15 +60,17 @@ impl HaCKS {
     
         // Render modules that end at this level
         if let Some(terminal_entries) = groups.remove(&None) {
-            for (_path, type_id) in terminal_entries {
+            let order = self.sort_for_menu(terminal_entries.iter().map(|(_, id)| *id).collect());
+            for type_id in order {
                 if let Some(module_rc) = self.hacs.get(&type_id) {
                     // Mutable borrow for actions
                     let mut module = module_rc.borrow_mut();
-                    if module.is_menu_enabled() && !module.is_window_enabled() {
+                    if self.is_phase_enabled(&*module, Phase::Menu) && !self.is_phase_enabled(&*module, Phase::Window) {
                         let name = module.name().to_string();
                 
                         ui.menu(name, || {
-                            module.render_menu(ui); // render_menu can be called on mutable borrow if it mutates state
+                            // render_menu can be called on mutable borrow if it mutates state
+                            self.guard(&type_id, "render_menu", || module.render_menu(ui));
                         });
                 
                         let button_label = format!("Window##undock_win_{:?}", type_id);
@@ -76,6 +78,13 @@ impl HaCKS {
                             module.set_show_window(true);
                             module.set_show_menu(false);
                         }
+                        ui.same_line();
+                        let pinned = module.metadata().menu_pinned;
+                        let pin_label = format!("{}##pin_win_{:?}", if pinned { "Unpin" } else { "Pin" }, type_id);
+                        if ui.small_button(&pin_label) {
+                            module.metadata_mut().menu_pinned = !pinned;
+                            *self.menu_dirty.borrow_mut() = true;
+                        }
                     }
                 }
             }
@@ -104,7 +113,8 @@ impl HaCKS {
         let cache = self.menu_cache.take().unwrap();
         let tracking_enabled = self.state_tracker.borrow().enabled;
      
-        for (top_name, entries) in cache.top_level.iter() {
+        for top_name in cache.top_order.iter() {
+            let Some(entries) = cache.top_level.get(top_name) else { continue; };
             let top_path = vec![top_name.clone()];
             let is_windowed = self.windowed_groups.borrow().get(&top_path).copied().unwrap_or(false);
             
@@ -131,25 +141,22 @@ impl HaCKS {
         tracking_enabled: bool,
     ) {
         // Similar to render_grouped_entries but with tracking
+        let order = self.sort_for_menu(entries.iter().map(|(_, id)| *id).collect());
         let mut sorted_entries: Vec<_> = entries.to_vec();
-        sorted_entries.sort_by(|a, b| {
-            let weight_a = self.hacs.get(&a.1).map(|m| m.borrow().menu_weight()).unwrap_or(0.0);
-            let weight_b = self.hacs.get(&b.1).map(|m| m.borrow().menu_weight()).unwrap_or(0.0);
-            weight_b.partial_cmp(&weight_a).unwrap_or(std::cmp::Ordering::Equal)
-        });
+        sorted_entries.sort_by_key(|(_, id)| order.iter().position(|o| o == id).unwrap_or(usize::MAX));
 
         for (path, type_id) in sorted_entries {
             if depth >= path.len() {
                 if let Some(module_rc) = self.hacs.get(&type_id) {
                     let mut module = module_rc.borrow_mut();
-                    if module.is_menu_enabled() && !module.is_window_enabled() {
+                    if self.is_phase_enabled(&*module, Phase::Menu) && !self.is_phase_enabled(&*module, Phase::Window) {
                         if tracking_enabled {
                             if let Some(tracker) = self.state_tracker.borrow_mut().get_tracker_mut(&type_id) {
                                 tracker.begin_render_menu();
                             }
                         }
                         
-                        module.render_menu(ui);
+                        self.guard(&type_id, "render_menu", || module.render_menu(ui));
                         
                         if tracking_enabled {
                             if let Some(tracker) = self.state_tracker.borrow_mut().get_tracker_mut(&type_id) {
@@ -163,6 +170,13 @@ impl HaCKS {
                             module.set_show_window(true);
                             module.set_show_menu(false);
                         }
+                        ui.same_line();
+                        let pinned = module.metadata().menu_pinned;
+                        let pin_label = format!("{}##pin_{:?}", if pinned { "Unpin" } else { "Pin" }, type_id);
+                        if ui.small_button(&pin_label) {
+                            module.metadata_mut().menu_pinned = !pinned;
+                            *self.menu_dirty.borrow_mut() = true;
+                        }
                     }
                 }
             } else {
@@ -193,14 +207,22 @@ impl HaCKS {
      pub fn render_window(&mut self, ui: &Ui) {
         let type_ids: Vec<_> = self.hacs.keys().copied().collect();
         let sorted: Vec<TypeId> = self.sort_by_weight(type_ids, |m| m.borrow().window_weight()).clone();
-        let scale = ui.current_font_size() / 14.0;
+        let scale = crate::gui::ui_scale(ui);
         let tracking_enabled = self.state_tracker.borrow().enabled;
 
         for type_id in sorted {
             if let Some(module_rc) = self.hacs.get(&type_id) {
+                if self.is_module_disabled(&type_id) {
+                    continue;
+                }
                 let mut module = module_rc.borrow_mut();
-                let mut show = module.is_window_enabled();
                 let name = module.name().to_string();
+                // Forced off by the host: leave the module's own window/menu flags alone
+                if self.phase_override(&name, Phase::Window) == Some(false) {
+                    continue;
+                }
+                let was_open = self.is_phase_enabled(&*module, Phase::Window);
+                let mut show = was_open;
 
                 if show {
                     let metadata = module.metadata();
@@ -210,13 +232,21 @@ impl HaCKS {
                         metadata.window_pos
                     };
 
+                    let request = self.window_requests.borrow_mut().remove(&type_id).unwrap_or_default();
+
                     let mut window = ui.window(name)
                         .opened(&mut show)
-                        .resizable(true)
-                        .position(saved_pos, Condition::FirstUseEver);
+                        .resizable(true);
+
+                    window = match request.pos {
+                        Some(pos) => window.position(pos, Condition::Always),
+                        None => window.position(saved_pos, Condition::FirstUseEver),
+                    };
 
                     if metadata.auto_resize_window {
                         window = window.always_auto_resize(true);
+                    } else if let Some(size) = request.size {
+                        window = window.size(size, Condition::Always);
                     } else {
                         let scaled_size = [
                             metadata.window_size[0] * scale,
@@ -232,7 +262,7 @@ impl HaCKS {
                             }
                         }
                         
-                        module.render_window(ui);
+                        self.guard(&type_id, "render_window", || module.render_window(ui));
                         
                         if tracking_enabled {
                             if let Some(tracker) = self.state_tracker.borrow_mut().get_tracker_mut(&type_id) {
@@ -252,10 +282,50 @@ impl HaCKS {
                     module.set_show_window(show);
                     module.set_show_menu(!show);
                 }
+
+                if was_open && !show {
+                    // Closing by hand beats a host override that forced it open
+                    if self.phase_override(&name, Phase::Window) == Some(true) {
+                        self.clear_phase_override(&name, Phase::Window);
+                    }
+                    self.guard(&type_id, "on_window_closed", || module.on_window_closed());
+                }
             }
         }
     }
 
+    /// Run one module's `render_draw_with_camera` into caller-supplied draw
+    /// lists, e.g. a child window's list for a minimap or thumbnail, with
+    /// `camera` mapping the module's world space into that child.
+    ///
+    /// Meant for the host, which owns the `HaCKS`: call it in the frame's
+    /// draw pass, after `render_draw` and outside any module callback.
+    /// Modules can't call it themselves since their render hooks get no
+    /// `&HaCKS`. Returns false if no module has that name or it is already
+    /// borrowed (e.g. called while that module is running).
+    pub fn render_module_to(
+        &self,
+        name: &str,
+        ui: &imgui::Ui,
+        draw_list_fg: &mut DrawListMut,
+        draw_list_bg: &mut DrawListMut,
+        camera: &Camera2D,
+    ) -> bool {
+        for (type_id, module_rc) in &self.hacs {
+            let Ok(mut module) = module_rc.try_borrow_mut() else {
+                continue;
+            };
+            if module.name() == name {
+                return self
+                    .guard(type_id, "render_draw", || {
+                        module.render_draw_with_camera(ui, draw_list_fg, draw_list_bg, camera)
+                    })
+                    .is_some();
+            }
+        }
+        false
+    }
+
     pub fn render_draw(
         &mut self,
         ui: &imgui::Ui,
@@ -275,7 +345,14 @@ impl HaCKS {
         for type_id in &sorted {
             if let Some(module_rc) = self.hacs.get(type_id) {
                 let module = module_rc.borrow();
-                if !module.is_render_enabled() {
+                if !self.is_phase_enabled(&*module, Phase::Render) {
+                    continue;
+                }
+                let meta = module.metadata();
+                if !meta.draws_background && !meta.draws_foreground {
+                    continue;
+                }
+                if !module.capabilities().contains(HaCKCapabilities::DRAW) {
                     continue;
                 }
                 if tracking_enabled {
@@ -304,7 +381,7 @@ impl HaCKS {
                     }
                 }
                 
-                module.render_draw(ui, draw_list_fg, draw_list_bg);
+                self.guard(&type_id, "render_draw", || module.render_draw(ui, draw_list_fg, draw_list_bg));
                 
                 if tracking_enabled {
                     if let Some(tracker) = self.state_tracker.borrow_mut().get_tracker_mut(&type_id) {
@@ -325,7 +402,7 @@ impl HaCKS {
                         }
                     }
                     
-                    module.render_draw(ui, draw_list_fg, draw_list_bg);
+                    self.guard(&type_id, "render_draw", || module.render_draw(ui, draw_list_fg, draw_list_bg));
                     
                     if tracking_enabled {
                         if let Some(tracker) = self.state_tracker.borrow_mut().get_tracker_mut(&type_id) {
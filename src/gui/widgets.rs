@@ -1,6 +1,22 @@
 use imgui::Ui;
 use std::fmt::Display;
 
+/// Font size the built-in layouts were designed at
+pub const BASE_FONT_SIZE: f32 = 14.0;
+
+/// Multiplier for pixel constants (sizes, offsets, indents) so layouts follow
+/// the font size and DPI scaling the host configured. 1.0 at the base size.
+pub fn ui_scale(ui: &Ui) -> f32 {
+    let size = ui.current_font_size();
+    if size > 0.0 { size / BASE_FONT_SIZE } else { 1.0 }
+}
+
+/// Settings panel for a config struct, usually generated with `#[derive(ConfigUi)]`
+pub trait ConfigUi {
+    /// Draw one widget per field; returns true if any value changed
+    fn render_config(&mut self, ui: &Ui) -> bool;
+}
+
 /// Trait for types that can be iterated as enums
 pub trait IntoEnumIterator: Sized {
     type Iterator: Iterator<Item = Self>;
@@ -103,4 +119,106 @@ where
     }
     
     changed
-}
\ No newline at end of file
+}
+/// Indeterminate loading indicator: a rotating arc, phased off imgui's clock
+/// so it needs no state. Occupies a `2 * radius` square at the cursor.
+pub fn spinner(ui: &Ui, radius: f32, thickness: f32, color: [f32; 4]) {
+    const SEGMENTS: usize = 24;
+    const ARC: f32 = std::f32::consts::PI * 1.5;
+
+    let origin = ui.cursor_screen_pos();
+    let center = [origin[0] + radius, origin[1] + radius];
+    let start = ui.time() as f32 * 6.0;
+
+    let points: Vec<[f32; 2]> = (0..=SEGMENTS)
+        .map(|i| {
+            let a = start + ARC * i as f32 / SEGMENTS as f32;
+            let r = radius - thickness * 0.5;
+            [center[0] + a.cos() * r, center[1] + a.sin() * r]
+        })
+        .collect();
+
+    ui.get_window_draw_list()
+        .add_polyline(points, color)
+        .thickness(thickness)
+        .build();
+    ui.dummy([radius * 2.0, radius * 2.0]);
+}
+
+/// Collapsible section with caller-owned open state (so it can be saved with
+/// module settings). Runs `body` indented while open; returns whether it is open.
+pub fn section<F: FnOnce()>(ui: &Ui, id: &str, label: &str, open: &mut bool, body: F) -> bool {
+    {
+        let _id = ui.push_id(id);
+        let dir = if *open { imgui::Direction::Down } else { imgui::Direction::Right };
+        if ui.arrow_button("##toggle", dir) {
+            *open = !*open;
+        }
+        ui.same_line();
+        if ui.selectable(label) {
+            *open = !*open;
+        }
+    }
+
+    if *open {
+        ui.indent();
+        body();
+        ui.unindent();
+    }
+    *open
+}
+
+/// Editable list of strings: each item with a remove button, then an input
+/// and "Add" button. Empty or duplicate entries are not added. Returns true
+/// if `items` changed.
+pub fn string_list_editor(ui: &Ui, id: &str, items: &mut Vec<String>, new_buf: &mut String) -> bool {
+    let _id = ui.push_id(id);
+    let mut changed = false;
+    let mut to_remove: Option<usize> = None;
+
+    for (idx, item) in items.iter().enumerate() {
+        let _row = ui.push_id_usize(idx);
+        if ui.small_button("X") {
+            to_remove = Some(idx);
+        }
+        ui.same_line();
+        ui.text(item);
+    }
+
+    if let Some(idx) = to_remove {
+        items.remove(idx);
+        changed = true;
+    }
+
+    let entered = ui.input_text("##new_item", new_buf)
+        .enter_returns_true(true)
+        .build();
+    ui.same_line();
+    if ui.button("Add") || entered {
+        let value = new_buf.trim();
+        if !value.is_empty() && !items.iter().any(|i| i == value) {
+            items.push(value.to_string());
+            changed = true;
+        }
+        new_buf.clear();
+    }
+
+    changed
+}
+
+/// RGBA color editor showing HSV inputs; returns true if changed
+pub fn color_edit_hsv(ui: &Ui, label: &str, color: &mut [f32; 4]) -> bool {
+    ui.color_edit4_config(label, color)
+        .display_mode(imgui::ColorEditDisplayMode::Hsv)
+        .build()
+}
+
+/// Full RGBA picker with RGB, HSV and hex inputs; returns true if changed
+pub fn color_picker(ui: &Ui, label: &str, color: &mut [f32; 4]) -> bool {
+    ui.color_picker4_config(label, color)
+        .alpha_bar(true)
+        .display_rgb(true)
+        .display_hsv(true)
+        .display_hex(true)
+        .build()
+}
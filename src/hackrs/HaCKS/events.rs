@@ -1,5 +1,7 @@
 use std::any::TypeId;
 
+use crate::CommandError;
+
 #[derive(Debug, Clone)]
 pub enum HaCSEvent {
     /// Ask a module to open its window
@@ -12,6 +14,18 @@ pub enum HaCSEvent {
     RedockGroup { path: Vec<String> },
     /// Rebuild the menu cache
     RebuildMenu,
+    /// Resize a module's window on the next frame (screen pixels)
+    ResizeWindow { module_id: TypeId, size: [f32; 2] },
+    /// Move a module's window on the next frame (screen pixels)
+    MoveWindow { module_id: TypeId, pos: [f32; 2] },
+}
+
+/// Pending one-shot geometry change for a module window.
+/// Applied with `Condition::Always` for the single frame after it was requested.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct WindowRequest {
+    pub pos: Option<[f32; 2]>,
+    pub size: Option<[f32; 2]>,
 }
 
 impl crate::HaCKS {
@@ -21,6 +35,36 @@ impl crate::HaCKS {
         self.event_bus.borrow_mut().push(event);
     }
 
+    /// Ask for a module's window to be resized next frame.
+    /// Has no visible effect while the module uses `auto_resize_window`.
+    pub fn request_window_size(&self, module_id: TypeId, size: [f32; 2]) {
+        self.emit(HaCSEvent::ResizeWindow { module_id, size });
+    }
+
+    /// Ask for a module's window to be moved next frame.
+    pub fn request_window_pos(&self, module_id: TypeId, pos: [f32; 2]) {
+        self.emit(HaCSEvent::MoveWindow { module_id, pos });
+    }
+
+    /// Run `cmd` on the module named `module_name` right away.
+    ///
+    /// Returns an error instead of panicking if that module is already
+    /// borrowed, e.g. when a module tries to command itself mid-update
+    /// (a mutably borrowed module can't be matched by name, so that case
+    /// reports `UnknownModule`).
+    pub fn execute_command(&self, module_name: &str, cmd: &str, args: &str) -> Result<String, CommandError> {
+        let (type_id, module_rc) = self.hacs
+            .iter()
+            .find(|(_, m)| m.try_borrow().map(|m| m.name() == module_name).unwrap_or(false))
+            .ok_or_else(|| CommandError::UnknownModule(module_name.to_string()))?;
+
+        let mut module = module_rc
+            .try_borrow_mut()
+            .map_err(|_| CommandError::Failed(format!("{} is busy", module_name)))?;
+        self.guard(type_id, "execute_command", || module.execute_command(cmd, args))
+            .unwrap_or_else(|| Err(CommandError::Failed(format!("{} is disabled after a panic", module_name))))
+    }
+
     /// Handle and clear all queued events
     pub fn process_events(&self) {
         let mut events = Vec::new();
@@ -49,6 +93,12 @@ impl crate::HaCKS {
                 HaCSEvent::RebuildMenu => {
                     *self.menu_dirty.borrow_mut() = true;
                 }
+                HaCSEvent::ResizeWindow { module_id, size } => {
+                    self.window_requests.borrow_mut().entry(module_id).or_default().size = Some(size);
+                }
+                HaCSEvent::MoveWindow { module_id, pos } => {
+                    self.window_requests.borrow_mut().entry(module_id).or_default().pos = Some(pos);
+                }
             }
         }
     }
@@ -214,3 +264,65 @@ impl crate::HaCKS {
 //         // Default: no-op
 //     }
 // }
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::{CommandError, HaCK, HaCKS, HaCMetadata};
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct Spawner {
+        hac_data: HaCMetadata,
+        spawned: u32,
+    }
+
+    impl HaCK for Spawner {
+        fn name(&self) -> &str {
+            "Spawner"
+        }
+
+        fn execute_command(&mut self, cmd: &str, args: &str) -> Result<String, CommandError> {
+            match cmd {
+                "spawn_rolling" => {
+                    let args: serde_json::Value = serde_json::from_str(args)
+                        .map_err(|e| CommandError::InvalidArgs(e.to_string()))?;
+                    let count = args["count"].as_u64().ok_or_else(|| CommandError::InvalidArgs("count".into()))?;
+                    self.spawned += count as u32;
+                    Ok(format!("spawned {}", self.spawned))
+                }
+                _ => Err(CommandError::UnknownCommand(cmd.to_string())),
+            }
+        }
+
+        crate::impl_hac_boilerplate!(Spawner, hac_data);
+    }
+
+    #[test]
+    fn dispatches_known_command() {
+        let mut hacs = HaCKS::new();
+        hacs.register(Spawner::default());
+
+        assert_eq!(hacs.execute_command("Spawner", "spawn_rolling", r#"{"count": 2}"#), Ok("spawned 2".into()));
+        assert_eq!(hacs.execute_command("Spawner", "spawn_rolling", r#"{"count": 3}"#), Ok("spawned 5".into()));
+    }
+
+    #[test]
+    fn reports_unknown_module_command_and_bad_args() {
+        let mut hacs = HaCKS::new();
+        hacs.register(Spawner::default());
+
+        assert_eq!(
+            hacs.execute_command("Nope", "spawn_rolling", "{}"),
+            Err(CommandError::UnknownModule("Nope".into()))
+        );
+        assert_eq!(
+            hacs.execute_command("Spawner", "reload", "{}"),
+            Err(CommandError::UnknownCommand("reload".into()))
+        );
+        assert!(matches!(
+            hacs.execute_command("Spawner", "spawn_rolling", "not json"),
+            Err(CommandError::InvalidArgs(_))
+        ));
+    }
+}
@@ -0,0 +1,130 @@
+use std::any::{Any, TypeId};
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::debug::LogOnce;
+
+impl crate::HaCKS {
+    /// Run one call into a module, catching a panic instead of letting it
+    /// unwind through the host.
+    ///
+    /// A caught panic counts as a failure in the module's `ModuleHealth`;
+    /// while the host has it disabled, `guard` returns `None` without
+    /// calling it and the menu/window/draw/update loops skip it.
+    ///
+    /// Unwinding across a dylib/FFI boundary is undefined behavior, so
+    /// plugins loaded that way should be built with `panic = "abort"`; this
+    /// guard covers modules compiled into the host and any plugin that does
+    /// unwind.
+    pub fn guard<R>(&self, type_id: &TypeId, call: &str, f: impl FnOnce() -> R) -> Option<R> {
+        if self.is_module_disabled(type_id) {
+            return None;
+        }
+        match panic::catch_unwind(AssertUnwindSafe(f)) {
+            Ok(value) => Some(value),
+            Err(payload) => {
+                let message = panic_message(payload.as_ref());
+                // The caller usually still holds the module borrowed, so fall
+                // back to the name the state tracker registered
+                let name = self
+                    .hacs
+                    .get(type_id)
+                    .and_then(|m| m.try_borrow().ok().map(|m| m.name().to_string()))
+                    .or_else(|| {
+                        let tracker = self.state_tracker.try_borrow().ok()?;
+                        tracker.get_tracker(type_id).map(|t| t.name.clone())
+                    })
+                    .unwrap_or_else(|| format!("{:?}", type_id));
+                LogOnce::error("panic", format!("{} panicked in {}: {}", name, call, message));
+                self.record_module_failure(type_id, &name, format!("panicked in {}: {}", call, message));
+                None
+            }
+        }
+    }
+}
+
+fn panic_message(payload: &dyn Any) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::hackrs::HaCKS::TickTarget;
+    use crate::{HaCK, HaCKS, HaCMetadata};
+
+    thread_local! {
+        static STEADY_UPDATES: Cell<u32> = Cell::new(0);
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct Exploding {
+        hac_data: HaCMetadata,
+    }
+
+    impl HaCK for Exploding {
+        fn name(&self) -> &str {
+            "Exploding"
+        }
+
+        fn update(&mut self, _hacs: &HaCKS) {
+            panic!("boom in update");
+        }
+
+        crate::impl_hac_boilerplate!(Exploding, hac_data);
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct Steady {
+        hac_data: HaCMetadata,
+    }
+
+    impl HaCK for Steady {
+        fn name(&self) -> &str {
+            "Steady"
+        }
+
+        fn update(&mut self, _hacs: &HaCKS) {
+            STEADY_UPDATES.with(|c| c.set(c.get() + 1));
+        }
+
+        crate::impl_hac_boilerplate!(Steady, hac_data);
+    }
+
+    #[test]
+    fn guard_catches_panic_and_records_it() {
+        let mut hacs = HaCKS::new();
+        hacs.register(Exploding::default());
+        let type_id = TypeId::of::<Exploding>();
+
+        assert_eq!(hacs.guard(&type_id, "probe", || 7), Some(7));
+        assert_eq!(hacs.guard(&type_id, "probe", || -> u32 { panic!("boom") }), None);
+
+        let health = hacs.module_health(&type_id);
+        assert_eq!(health.failures, 1);
+        assert_eq!(health.last_error.as_deref(), Some("panicked in probe: boom"));
+        assert!(hacs.is_module_disabled(&type_id));
+    }
+
+    #[test]
+    fn panicking_update_does_not_stop_other_modules() {
+        let mut hacs = HaCKS::new();
+        hacs.register(Exploding::default());
+        hacs.register(Steady::default());
+        STEADY_UPDATES.with(|c| c.set(0));
+
+        hacs.tick(TickTarget::All);
+        assert_eq!(STEADY_UPDATES.with(|c| c.get()), 1);
+        assert_eq!(hacs.module_health(&TypeId::of::<Exploding>()).failures, 1);
+        assert!(hacs.module_health(&TypeId::of::<Steady>()).is_ok());
+    }
+}
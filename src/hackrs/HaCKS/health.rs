@@ -0,0 +1,188 @@
+use std::any::TypeId;
+use std::time::{Duration, Instant};
+
+use crate::debug::LogOnce;
+
+/// Failures allowed before a module is disabled until re-enabled by hand
+pub const DEFAULT_MAX_MODULE_FAILURES: u32 = 3;
+
+/// How long a module is skipped after each failure below the limit, multiplied by the failure count
+const FAILURE_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Error state the host keeps per module.
+///
+/// A module with no failures is healthy. Each failure backs it off for a
+/// while; once `failures` reaches the host's limit it is quarantined and
+/// only `HaCKS::reenable_module` brings it back.
+///
+/// Measured on wall time, so backoff expires whether or not the host drives
+/// the shared frame clock.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ModuleHealth {
+    pub failures: u32,
+    pub last_error: Option<String>,
+    /// Skipped until this instant while backing off
+    pub disabled_until: Option<Instant>,
+    /// Skipped until re-enabled by hand
+    pub quarantined: bool,
+}
+
+impl ModuleHealth {
+    pub fn is_ok(&self) -> bool {
+        self.failures == 0
+    }
+
+    pub fn is_disabled(&self, now: Instant) -> bool {
+        self.quarantined || self.disabled_until.is_some_and(|until| now < until)
+    }
+
+    /// Count one failure at `now`; returns true if this one reached `max` and quarantined the module.
+    pub fn record_failure(&mut self, error: impl Into<String>, now: Instant, max: u32) -> bool {
+        self.failures += 1;
+        self.last_error = Some(error.into());
+        if self.failures >= max {
+            self.quarantined = true;
+            self.disabled_until = None;
+            true
+        } else {
+            self.disabled_until = Some(now + FAILURE_BACKOFF * self.failures);
+            false
+        }
+    }
+
+    /// Short status for the editor
+    pub fn status_text(&self, now: Instant) -> String {
+        if self.last_error.is_none() {
+            return "OK".to_string();
+        }
+        if self.quarantined {
+            return format!("Disabled after {} failures", self.failures);
+        }
+        match self.disabled_until {
+            Some(until) if now < until => format!(
+                "Errored ({}), retrying in {:.1}s",
+                self.failures,
+                (until - now).as_secs_f32()
+            ),
+            _ => format!("Errored ({})", self.failures),
+        }
+    }
+}
+
+impl crate::HaCKS {
+    /// Count a failure against a module (caught panic, failed load, ...).
+    ///
+    /// Below the limit the module is skipped for a growing backoff; at the
+    /// limit it stays disabled until `reenable_module`.
+    pub fn record_module_failure(&self, type_id: &TypeId, name: &str, error: impl Into<String>) {
+        let error = error.into();
+        let max = *self.max_module_failures.borrow();
+
+        let mut health = self.health.borrow_mut();
+        let entry = health.entry(*type_id).or_default();
+        if entry.record_failure(error.clone(), Instant::now(), max) {
+            LogOnce::error("health", format!("{} disabled after {} failures: {}", name, entry.failures, error));
+        } else {
+            LogOnce::warn("health", format!("{} failed ({}/{}): {}", name, entry.failures, max, error));
+        }
+        *self.menu_dirty.borrow_mut() = true;
+    }
+
+    pub fn module_health(&self, type_id: &TypeId) -> ModuleHealth {
+        self.health.borrow().get(type_id).cloned().unwrap_or_default()
+    }
+
+    /// Whether the host is currently skipping this module
+    pub fn is_module_disabled(&self, type_id: &TypeId) -> bool {
+        let now = Instant::now();
+        self.health
+            .borrow()
+            .get(type_id)
+            .is_some_and(|h| h.is_disabled(now))
+    }
+
+    /// Forget a module's failures so it is called again
+    pub fn reenable_module(&self, type_id: &TypeId) {
+        if self.health.borrow_mut().remove(type_id).is_some() {
+            *self.menu_dirty.borrow_mut() = true;
+        }
+    }
+
+    /// Failures before a module is disabled for good; at least 1
+    pub fn set_max_module_failures(&self, max: u32) {
+        *self.max_module_failures.borrow_mut() = max.max(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::hackrs::HaCKS::TickTarget;
+    use crate::{HaCK, HaCKS, HaCMetadata};
+
+    thread_local! {
+        static UPDATE_CALLS: Cell<u32> = Cell::new(0);
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct Panicky {
+        hac_data: HaCMetadata,
+    }
+
+    impl HaCK for Panicky {
+        fn name(&self) -> &str {
+            "Panicky"
+        }
+
+        fn update(&mut self, _hacs: &HaCKS) {
+            UPDATE_CALLS.with(|c| c.set(c.get() + 1));
+            panic!("deliberate test panic");
+        }
+
+        crate::impl_hac_boilerplate!(Panicky, hac_data);
+    }
+
+    #[test]
+    fn backs_off_then_quarantines_after_max_failures() {
+        let start = Instant::now();
+        let mut health = ModuleHealth::default();
+        assert!(health.is_ok());
+
+        assert!(!health.record_failure("first", start, 3));
+        assert!(health.is_disabled(start));
+        assert!(!health.is_disabled(start + FAILURE_BACKOFF));
+
+        let later = start + FAILURE_BACKOFF;
+        assert!(!health.record_failure("second", later, 3));
+        assert!(health.is_disabled(later + FAILURE_BACKOFF));
+        assert!(!health.is_disabled(later + FAILURE_BACKOFF * 2));
+
+        assert!(health.record_failure("third", later, 3));
+        assert!(health.is_disabled(later + Duration::from_secs(3600)));
+        assert_eq!(health.last_error.as_deref(), Some("third"));
+    }
+
+    #[test]
+    fn panicking_module_is_disabled_and_reenabled() {
+        let mut hacs = HaCKS::new();
+        hacs.register(Panicky::default());
+        hacs.set_max_module_failures(1);
+        let type_id = TypeId::of::<Panicky>();
+        UPDATE_CALLS.with(|c| c.set(0));
+
+        hacs.tick(TickTarget::All);
+        hacs.tick(TickTarget::All);
+        assert_eq!(UPDATE_CALLS.with(|c| c.get()), 1);
+        assert!(hacs.is_module_disabled(&type_id));
+        assert!(hacs.module_health(&type_id).quarantined);
+
+        hacs.reenable_module(&type_id);
+        assert!(hacs.module_health(&type_id).is_ok());
+        hacs.tick(TickTarget::All);
+        assert_eq!(UPDATE_CALLS.with(|c| c.get()), 2);
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{hack::HaCK, HaCKS};
+use std::any::TypeId;
+
+use crate::{hack::{HaCK, HaCKCapabilities}, HaCKS, HaCMetadata};
 
 pub trait ModuleIterable {
     fn for_each_module_mut<F: FnMut(&mut dyn HaCK)>(&mut self, f: F);
@@ -7,16 +9,12 @@ pub trait ModuleIterable {
 
 
 impl ModuleIterable for HaCKS {
-    fn for_each_module_mut<F: FnMut(&mut dyn HaCK)>(&mut self, mut f: F) {
-        for module in self.hacs.values() {
-            f(&mut *module.borrow_mut());
-        }
+    fn for_each_module_mut<F: FnMut(&mut dyn HaCK)>(&mut self, f: F) {
+        HaCKS::for_each_module_mut(self, f);
     }
 
-    fn for_each_module<F: FnMut(&dyn HaCK)>(&self, mut f: F) {
-        for module in self.hacs.values() {
-            f(&*module.borrow());
-        }
+    fn for_each_module<F: FnMut(&dyn HaCK)>(&self, f: F) {
+        HaCKS::for_each_module(self, f);
     }
 }
 
@@ -29,15 +27,62 @@ impl HaCKS {
         self.hacs.values().map(|m| m.borrow_mut())
     }
 
+    /// Module ids in the same order the menu lists them (see
+    /// [`HaCKS::sort_for_menu`]: pinned first, then weight, then name).
+    ///
+    /// Safe to call from inside a module's `update`/`render_*` through
+    /// `&HaCKS`: modules that are already mutably borrowed, including the
+    /// caller, are skipped rather than panicking. The same holds for
+    /// `metadata_list`, `modules_with_capability` and `for_each_module`.
+    pub fn menu_ordered_ids(&self) -> Vec<TypeId> {
+        self.sort_for_menu(self.hacs.keys().copied().collect())
+    }
+
+    /// Cloned metadata of every registered module that isn't busy, in menu
+    /// order. Nothing stays borrowed after this returns.
+    pub fn metadata_list(&self) -> Vec<HaCMetadata> {
+        self.menu_ordered_ids()
+            .into_iter()
+            .filter_map(|id| Some(self.hacs.get(&id)?.try_borrow().ok()?.metadata().clone()))
+            .collect()
+    }
+
+    /// Ids of modules declaring all of `caps`, in menu order; busy modules are skipped.
+    pub fn modules_with_capability(&self, caps: HaCKCapabilities) -> Vec<TypeId> {
+        self.menu_ordered_ids()
+            .into_iter()
+            .filter(|id| {
+                self.hacs
+                    .get(id)
+                    .and_then(|m| m.try_borrow().ok().map(|m| m.capabilities().contains(caps)))
+                    .unwrap_or(false)
+            })
+            .collect()
+    }
+
+    /// Visit every module in menu order, skipping any that are mutably
+    /// borrowed elsewhere.
+    ///
+    /// Each module is borrowed only while its own callback runs, so `f` may
+    /// look up *other* modules through `get_module`, but mutably borrowing
+    /// the module currently being visited will panic.
     pub fn for_each_module<F: FnMut(&dyn HaCK)>(&self, mut f: F) {
-        for module in self.hacs.values() {
-            f(&*module.borrow());
+        for id in self.menu_ordered_ids() {
+            if let Some(Ok(module)) = self.hacs.get(&id).map(|m| m.try_borrow()) {
+                f(&*module);
+            }
         }
     }
 
+    /// Mutable variant of [`HaCKS::for_each_module`]. Modules borrowed
+    /// elsewhere (shared or mutable) are skipped; the visited module is
+    /// mutably borrowed during its callback, so `f` must not access it
+    /// again through `self`.
     pub fn for_each_module_mut<F: FnMut(&mut dyn HaCK)>(&self, mut f: F) {
-        for module in self.hacs.values() {
-            f(&mut *module.borrow_mut());
+        for id in self.menu_ordered_ids() {
+            if let Some(Ok(mut module)) = self.hacs.get(&id).map(|m| m.try_borrow_mut()) {
+                f(&mut *module);
+            }
         }
     }
-}
\ No newline at end of file
+}
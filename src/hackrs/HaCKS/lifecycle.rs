@@ -1,6 +1,6 @@
 use std::any::{TypeId};
 
-use crate::{HaCKS};
+use crate::{HaCKCapabilities, HaCKS};
 
 impl HaCKS {
     pub fn get_init_data<T: 'static>(&self) -> Option<&T> {
@@ -25,7 +25,7 @@ impl HaCKS {
                     }
                 }
                 
-                module_rc.borrow_mut().init();
+                self.guard(&type_id, "init", || module_rc.borrow_mut().init());
                 
                 if tracking_enabled {
                     if let Some(tracker) = self.state_tracker.borrow_mut().get_tracker_mut(&type_id) {
@@ -36,9 +36,38 @@ impl HaCKS {
         }
     }
     
+    /// Start a frame. Call before `update`/`before_render`. Advances the shared
+    /// clock, then runs `on_frame_begin` in dependency order for modules
+    /// declaring `FRAME_HOOKS`.
+    pub fn begin_frame(&self) {
+        self.clock.borrow_mut().tick();
+        *self.frame_begun.borrow_mut() = true;
+        for type_id in self.topological_sort_update() {
+            if let Some(module_rc) = self.hacs.get(&type_id) {
+                if !module_rc.borrow().capabilities().contains(HaCKCapabilities::FRAME_HOOKS) {
+                    continue;
+                }
+                self.guard(&type_id, "on_frame_begin", || module_rc.borrow_mut().on_frame_begin());
+            }
+        }
+    }
+
+    /// End a frame. Call after `render_menu`/`render_window`/`render_draw`.
+    /// Like `begin_frame`, only modules declaring `FRAME_HOOKS` are called.
+    pub fn end_frame(&self) {
+        for type_id in self.topological_sort_update() {
+            if let Some(module_rc) = self.hacs.get(&type_id) {
+                if !module_rc.borrow().capabilities().contains(HaCKCapabilities::FRAME_HOOKS) {
+                    continue;
+                }
+                self.guard(&type_id, "on_frame_end", || module_rc.borrow_mut().on_frame_end());
+            }
+        }
+    }
+
     pub fn exit_all(&self) {
-        for module_rc in self.hacs.values() {
-            module_rc.borrow_mut().exit();
+        for (type_id, module_rc) in &self.hacs {
+            self.guard(type_id, "exit", || module_rc.borrow_mut().exit());
         }
     }
 
@@ -13,6 +13,9 @@ pub mod lifecycle;
 pub mod iteration;
 pub mod search;
 pub mod events;
+pub mod phases;
+pub mod guard;
+pub mod health;
 
 
 pub use update::*;
@@ -21,8 +24,10 @@ pub use registry::*;
 pub use iteration::*;
 pub use search::*;
 pub use events::*;
+pub use phases::*;
+pub use health::*;
 
-use crate::{GlobalStateTracker, RuntimeSyncManager, SyncRegistry};
+use crate::{FrameClock, GlobalStateTracker, Rng, RuntimeSyncManager, SyncRegistry};
 use crate::access::AccessManager;
 use crate::gui::hotkey_manager::HotkeyManager;
 
@@ -44,6 +49,7 @@ pub struct HaCKS {
     
     pub show_debug_window: RefCell<bool>,
     pub windowed_groups: RefCell<HashMap<Vec<String>, bool>>,
+    pub window_requests: RefCell<HashMap<TypeId, WindowRequest>>,
     pub metadata_window: RefCell<bool>,
     pub viz_mode: RefCell<u32>,
     pub metadata_window_viz: RefCell<bool>,
@@ -53,6 +59,20 @@ pub struct HaCKS {
     pub sync_registry: RefCell<Option<SyncRegistry>>,
     pub runtime_sync_manager: RefCell<Option<RuntimeSyncManager>>,
     pub state_tracker: RefCell<GlobalStateTracker>,
+    /// Shared seedable RNG; the host owns the seed for replay/testing
+    pub rng: RefCell<Rng>,
+    /// Shared timeline; read this instead of `Instant::now()`
+    pub clock: RefCell<FrameClock>,
+    /// Set by `begin_frame` so `update` knows the clock already moved this frame
+    pub frame_begun: RefCell<bool>,
+    /// Per-module (last update time, dt since the one before) on `clock`
+    pub update_times: RefCell<HashMap<TypeId, (f64, f64)>>,
+    /// Host-side phase switches by module name; see `set_phase_enabled`
+    pub phase_overrides: RefCell<HashMap<String, HashMap<Phase, bool>>>,
+    /// Failure record for modules that have panicked or failed to load
+    pub health: RefCell<HashMap<TypeId, ModuleHealth>>,
+    /// Failures before a module is disabled until re-enabled
+    pub max_module_failures: RefCell<u32>,
 }
 
 #[allow(unused)]
@@ -69,6 +89,7 @@ impl HaCKS {
             show_debug_window: RefCell::new(false),
             metadata_window: RefCell::new(false),
             windowed_groups: RefCell::new(HashMap::new()),
+            window_requests: RefCell::new(HashMap::new()),
             viz_mode: RefCell::new(0),
             metadata_window_viz: RefCell::new(false),
             color_scheme: RefCell::new(0),
@@ -76,6 +97,13 @@ impl HaCKS {
             sync_registry: RefCell::new(None),
             runtime_sync_manager: RefCell::new(None),
             state_tracker: RefCell::new(GlobalStateTracker::new()),
+            rng: RefCell::new(Rng::default()),
+            clock: RefCell::new(FrameClock::new()),
+            frame_begun: RefCell::new(false),
+            update_times: RefCell::new(HashMap::new()),
+            phase_overrides: RefCell::new(HashMap::new()),
+            health: RefCell::new(HashMap::new()),
+            max_module_failures: RefCell::new(DEFAULT_MAX_MODULE_FAILURES),
         }
     }
 
@@ -114,6 +142,51 @@ impl HaCKS {
         *self.runtime_sync_manager.borrow_mut() = Some(manager);
     }
     
+    /// Restart the shared RNG sequence
+    pub fn seed_rng(&self, seed: u64) {
+        self.rng.borrow_mut().reseed(seed);
+    }
+
+    /// Uniform in `[0, 1)` from the shared RNG
+    pub fn random_f32(&self) -> f32 {
+        self.rng.borrow_mut().next_f32()
+    }
+
+    /// Uniform in `[min, max)` from the shared RNG
+    pub fn random_range(&self, min: f32, max: f32) -> f32 {
+        self.rng.borrow_mut().range_f32(min, max)
+    }
+
+    /// Seconds on the shared timeline (monotonic)
+    pub fn time_seconds(&self) -> f64 {
+        self.clock.borrow().time_seconds()
+    }
+
+    /// Seconds since the previous frame
+    pub fn delta_seconds(&self) -> f64 {
+        self.clock.borrow().delta_seconds()
+    }
+
+    /// Frames started since the clock was created
+    pub fn frame_index(&self) -> u64 {
+        self.clock.borrow().frame_index()
+    }
+
+    /// Current time scale; multiply any hand-rolled `dt` by this
+    pub fn time_scale(&self) -> f32 {
+        self.clock.borrow().time_scale()
+    }
+
+    /// Host-side control of the shared time scale (0.0 pauses)
+    pub fn set_time_scale(&self, scale: f32) {
+        self.clock.borrow_mut().set_time_scale(scale);
+    }
+
+    /// Replace the clock, e.g. with `FrameClock::manual()` in tests
+    pub fn set_clock(&self, clock: FrameClock) {
+        *self.clock.borrow_mut() = clock;
+    }
+
     /// Run all syncs
     pub fn sync_modules(&self) {
         // Run type-safe syncs
@@ -1,6 +1,94 @@
 use std::{any::TypeId, cell::RefCell, collections::HashMap, rc::Rc};
+use serde::{de::DeserializeOwned, Serialize};
 
-use crate::{HaCK, HaCKS};
+use crate::{HaCK, HaCKS, PHASE_OVERRIDES_KEY};
+
+fn settings_pretty_config() -> ron::ser::PrettyConfig {
+    ron::ser::PrettyConfig::new().depth_limit(4).struct_names(true)
+}
+
+/// Settings files are written as RON by `save_to_file`; older ones may be JSON.
+fn parse_settings<V: DeserializeOwned>(contents: &str) -> Result<V, String> {
+    let json_err = match serde_json::from_str(contents) {
+        Ok(value) => return Ok(value),
+        Err(e) => e,
+    };
+    ron::from_str(contents).map_err(|ron_err| format!("not valid RON ({}) or JSON ({})", ron_err, json_err))
+}
+
+/// Push `value` through the same JSON -> RON -> JSON path module settings take
+/// on save/load and panic if it comes back different.
+///
+/// Use it in a module's tests to catch fields that only survive one direction
+/// (a missing `#[serde(default)]`, `skip_serializing` without a matching default, ...):
+/// ```ignore
+/// hackers::hackrs::HaCKS::persistence::assert_roundtrip(&MyModule::default());
+/// ```
+pub fn assert_roundtrip<T>(value: &T)
+where
+    T: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug,
+{
+    let json = serde_json::to_value(value).expect("settings failed to serialize to JSON");
+    let text = ron::ser::to_string_pretty(&json, settings_pretty_config())
+        .expect("settings failed to serialize to RON");
+    let reparsed: serde_json::Value = parse_settings(&text)
+        .expect("saved settings failed to parse back");
+    let restored: T = serde_json::from_value(reparsed)
+        .expect("saved settings failed to deserialize back");
+
+    assert_eq!(&restored, value, "settings changed across a save/load round-trip");
+}
+
+/// Key stamped into saved settings by [`to_versioned_value`].
+pub const SETTINGS_VERSION_KEY: &str = "settings_version";
+
+/// Settings whose saved shape changes over time.
+///
+/// Saved JSON without a version stamp is treated as version 0.
+/// ```ignore
+/// impl Migratable for MyConfig {
+///     const VERSION: u32 = 1;
+///     fn migrate(mut old: serde_json::Value, from: u32) -> serde_json::Value {
+///         if from == 0 {
+///             // v1 added `bar_scale`
+///             old["bar_scale"] = serde_json::json!(1.0);
+///         }
+///         old
+///     }
+/// }
+/// ```
+pub trait Migratable {
+    /// Current settings version; bump it whenever fields are added, renamed or removed.
+    const VERSION: u32;
+
+    /// Upgrade JSON saved at version `from` by one step, to `from + 1`.
+    fn migrate(old: serde_json::Value, from: u32) -> serde_json::Value;
+}
+
+/// Serialize `value` and stamp it with `T::VERSION`.
+pub fn to_versioned_value<T: Migratable + Serialize>(value: &T) -> Result<serde_json::Value, serde_json::Error> {
+    let mut json = serde_json::to_value(value)?;
+    if let Some(obj) = json.as_object_mut() {
+        obj.insert(SETTINGS_VERSION_KEY.to_string(), T::VERSION.into());
+    }
+    Ok(json)
+}
+
+/// Run every migration between the saved version and `T::VERSION`, then deserialize.
+pub fn from_versioned_value<T: Migratable + DeserializeOwned>(mut json: serde_json::Value) -> Result<T, serde_json::Error> {
+    let mut version = json
+        .as_object_mut()
+        .and_then(|obj| obj.remove(SETTINGS_VERSION_KEY))
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0) as u32;
+
+    while version < T::VERSION {
+        json = T::migrate(json, version);
+        version += 1;
+    }
+
+    serde_json::from_value(json)
+}
 
 impl HaCKS {
 
@@ -12,15 +100,36 @@ impl HaCKS {
         container
     }
 
+    /// Load modules from a settings file written by [`HaCKS::save_to_file`].
+    ///
+    /// Returns the container, the raw settings map, and any problems the host
+    /// should surface to the user (unreadable or corrupt file). A missing file
+    /// is not an error; defaults are used silently. Pass `load_all_settings`
+    /// from `declare_and_register_hacs!`; use [`HaCKS::load_from_file_with_errors`]
+    /// to also hear about individual modules whose saved settings were unusable.
     pub fn load_from_file<F, P: AsRef<std::path::Path>>(
         path: P,
         mut load_settings_fn: F
     ) -> (Self, HashMap<String, serde_json::Value>, Vec<String>)
     where
         F: FnMut(&HashMap<String, serde_json::Value>) -> Vec<Rc<RefCell<dyn HaCK>>>,
+    {
+        Self::load_from_file_with_errors(path, |settings, _| load_settings_fn(settings))
+    }
+
+    /// [`HaCKS::load_from_file`] with a loader that reports per-module
+    /// problems into the returned list; pass `load_all_settings_with_errors`
+    /// from `declare_and_register_hacs!`.
+    pub fn load_from_file_with_errors<F, P: AsRef<std::path::Path>>(
+        path: P,
+        mut load_settings_fn: F
+    ) -> (Self, HashMap<String, serde_json::Value>, Vec<String>)
+    where
+        F: FnMut(&HashMap<String, serde_json::Value>, &mut Vec<String>) -> Vec<Rc<RefCell<dyn HaCK>>>,
     {
         let mut container = HaCKS::new();
         let mut extra_settings = HashMap::new();
+        let mut errors = Vec::new();
     
         let debug_path = path.as_ref().parent()
             .map(|p| p.join("persistence_debug.txt"))
@@ -28,36 +137,47 @@ impl HaCKS {
     
         let mut debug_log = format!("load_from_file: Reading from {:?}\n", path.as_ref());
     
-        if let Ok(contents) = std::fs::read_to_string(&path) {
-            debug_log.push_str(&format!("File read successfully, {} bytes\n", contents.len()));
-    
-            if let Ok(settings) = serde_json::from_str::<HashMap<String, serde_json::Value>>(&contents) {
-                debug_log.push_str(&format!("Parsed {} settings\n", settings.len()));
-                extra_settings = settings.clone();
-    
-                for module in load_settings_fn(&settings) {
-                    container.register_boxed(module);
+        match std::fs::read_to_string(&path) {
+            Ok(contents) => {
+                debug_log.push_str(&format!("File read successfully, {} bytes\n", contents.len()));
+
+                match parse_settings::<HashMap<String, serde_json::Value>>(&contents) {
+                    Ok(settings) => {
+                        debug_log.push_str(&format!("Parsed {} settings\n", settings.len()));
+                        extra_settings = settings.clone();
+
+                        for module in load_settings_fn(&settings, &mut errors) {
+                            container.register_boxed(module);
+                        }
+                        container.load_phase_overrides(&settings);
+
+                        debug_log.push_str(&format!("Registered {} modules\n", container.hacs.len()));
+                        let _ = std::fs::write(&debug_path, &debug_log);
+
+                        return (container, extra_settings, errors);
+                    }
+                    Err(e) => {
+                        debug_log.push_str(&format!("Failed to parse settings: {}\n", e));
+                        errors.push(format!("Settings file {:?} is corrupt, using defaults: {}", path.as_ref(), e));
+                    }
+                }
+            }
+            Err(e) => {
+                debug_log.push_str(&format!("Failed to read file: {}\n", e));
+                if e.kind() != std::io::ErrorKind::NotFound {
+                    errors.push(format!("Could not read settings file {:?}: {}", path.as_ref(), e));
                 }
-    
-                debug_log.push_str(&format!("Registered {} modules\n", container.hacs.len()));
-                let _ = std::fs::write(&debug_path, &debug_log);
-    
-                return (container, extra_settings, vec![]);
-            } else {
-                debug_log.push_str("Failed to parse JSON\n");
             }
-        } else {
-            debug_log.push_str("Failed to read file\n");
         }
     
         debug_log.push_str("Using defaults\n");
-        for module in load_settings_fn(&HashMap::new()) {
+        for module in load_settings_fn(&HashMap::new(), &mut errors) {
             container.register_boxed(module);
         }
         debug_log.push_str(&format!("Created {} default modules\n", container.hacs.len()));
         let _ = std::fs::write(&debug_path, debug_log);
     
-        (container, extra_settings, vec![])
+        (container, extra_settings, errors)
     }
     
 
@@ -76,9 +196,10 @@ impl HaCKS {
                 settings.insert(key.clone(), value.clone());
             }
         }
+        // After the extras, so a stale copy loaded with them can't win
+        settings.insert(PHASE_OVERRIDES_KEY.to_string(), self.phase_overrides_to_value());
 
-        let pretty_config = ron::ser::PrettyConfig::new().depth_limit(4).struct_names(true);
-        let contents = ron::ser::to_string_pretty(&settings, pretty_config)?;
+        let contents = ron::ser::to_string_pretty(&settings, settings_pretty_config())?;
         std::fs::write(path, contents)?;
         Ok(())
     }
@@ -132,3 +253,102 @@ impl HaCKS {
         }
 
 }
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use super::*;
+    use crate::Phase;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        bar_scale: f32,
+        label: String,
+    }
+
+    impl Migratable for Config {
+        const VERSION: u32 = 2;
+        fn migrate(mut old: serde_json::Value, from: u32) -> serde_json::Value {
+            match from {
+                // v1 added `bar_scale`
+                0 => old["bar_scale"] = json!(1.0),
+                // v2 renamed `name` to `label`
+                1 => {
+                    let name = old.as_object_mut().unwrap().remove("name").unwrap();
+                    old["label"] = name;
+                }
+                _ => {}
+            }
+            old
+        }
+    }
+
+    #[test]
+    fn unversioned_settings_run_every_migration() {
+        let restored: Config = from_versioned_value(json!({ "name": "hp" })).unwrap();
+        assert_eq!(restored, Config { bar_scale: 1.0, label: "hp".into() });
+    }
+
+    #[test]
+    fn versioned_settings_skip_applied_migrations() {
+        let saved = json!({ "settings_version": 1, "bar_scale": 2.0, "name": "mp" });
+        let restored: Config = from_versioned_value(saved).unwrap();
+        assert_eq!(restored, Config { bar_scale: 2.0, label: "mp".into() });
+    }
+
+    #[test]
+    fn versioned_roundtrip() {
+        let config = Config { bar_scale: 0.5, label: "xp".into() };
+        let saved = to_versioned_value(&config).unwrap();
+        assert_eq!(saved[SETTINGS_VERSION_KEY], json!(Config::VERSION));
+        let restored: Config = from_versioned_value(saved).unwrap();
+        assert_eq!(restored, config);
+    }
+
+    /// Shaped like a real module's settings: metadata plus its own config
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct OverlaySettings {
+        hac_data: crate::HaCMetadata,
+        radius: f32,
+        labels: Vec<String>,
+        anchor: Option<[f32; 2]>,
+    }
+
+    #[test]
+    fn saved_module_settings_reload_identically() {
+        assert_roundtrip(&OverlaySettings {
+            hac_data: crate::HaCMetadata::builder("Overlay")
+                .update_interval_ms(250)
+                .draw_layers(false, true)
+                .build(),
+            radius: 12.5,
+            labels: vec!["hp".into(), "name".into()],
+            anchor: Some([0.5, 1.0]),
+        });
+    }
+
+    #[test]
+    fn phase_overrides_survive_save_and_load() {
+        let path = std::env::temp_dir().join(format!("hackers_phase_overrides_{}.ron", std::process::id()));
+        let hacs = HaCKS::new();
+        hacs.set_phase_enabled("ESP", Phase::Render, false);
+        hacs.set_phase_enabled("Radar", Phase::Update, true);
+        hacs.save_to_file(&path, |_| HashMap::new(), None).unwrap();
+
+        let (loaded, _, errors) = HaCKS::load_from_file(&path, |_| Vec::new());
+        let _ = std::fs::remove_file(&path);
+
+        assert!(errors.is_empty(), "{:?}", errors);
+        assert_eq!(loaded.phase_override("ESP", Phase::Render), Some(false));
+        assert_eq!(loaded.phase_override("Radar", Phase::Update), Some(true));
+        assert_eq!(loaded.phase_override("ESP", Phase::Menu), None);
+    }
+
+    #[test]
+    fn parse_failure_reports_both_formats() {
+        let err = parse_settings::<HashMap<String, serde_json::Value>>("{ not settings").unwrap_err();
+        assert!(err.contains("RON") && err.contains("JSON"), "{}", err);
+    }
+}
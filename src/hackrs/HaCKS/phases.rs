@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::HaCK;
+
+/// Extra-settings key the host stores [`HaCKS::phase_overrides_to_value`] under.
+pub const PHASE_OVERRIDES_KEY: &str = "phase_overrides";
+
+/// Per-frame entry points a module can have switched on or off
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Phase {
+    Menu,
+    Window,
+    Render,
+    Update,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 4] = [Phase::Menu, Phase::Window, Phase::Render, Phase::Update];
+
+    fn module_flag(self, module: &dyn HaCK) -> bool {
+        match self {
+            Phase::Menu => module.is_menu_enabled(),
+            Phase::Window => module.is_window_enabled(),
+            Phase::Render => module.is_render_enabled(),
+            Phase::Update => module.is_update_enabled(),
+        }
+    }
+}
+
+impl crate::HaCKS {
+    /// Host-side override for one phase of the module named `name`.
+    ///
+    /// An override takes precedence over the module's own `is_*_enabled`
+    /// flag without changing it, so clearing the override restores whatever
+    /// the module had. Overrides are keyed by name so they survive reloads.
+    pub fn set_phase_enabled(&self, name: &str, phase: Phase, enabled: bool) {
+        self.phase_overrides
+            .borrow_mut()
+            .entry(name.to_string())
+            .or_default()
+            .insert(phase, enabled);
+        if phase == Phase::Menu {
+            *self.menu_dirty.borrow_mut() = true;
+        }
+    }
+
+    /// Drop an override so the module's own flag applies again
+    pub fn clear_phase_override(&self, name: &str, phase: Phase) {
+        let mut overrides = self.phase_overrides.borrow_mut();
+        if let Some(phases) = overrides.get_mut(name) {
+            phases.remove(&phase);
+            if phases.is_empty() {
+                overrides.remove(name);
+            }
+        }
+    }
+
+    pub fn phase_override(&self, name: &str, phase: Phase) -> Option<bool> {
+        self.phase_overrides
+            .borrow()
+            .get(name)
+            .and_then(|phases| phases.get(&phase).copied())
+    }
+
+    /// Whether the loops should run `phase` for `module`: never while the
+    /// host has it disabled for failing, else the host override if there is one,
+    /// otherwise the module's own flag.
+    pub fn is_phase_enabled(&self, module: &dyn HaCK, phase: Phase) -> bool {
+        if self.is_module_disabled(&module.nac_type_id()) {
+            return false;
+        }
+        self.phase_override(module.name(), phase)
+            .unwrap_or_else(|| phase.module_flag(module))
+    }
+
+    /// Overrides as JSON under [`PHASE_OVERRIDES_KEY`]; `save_to_file`
+    /// writes this and `load_from_file` reads it back
+    pub fn phase_overrides_to_value(&self) -> serde_json::Value {
+        serde_json::to_value(&*self.phase_overrides.borrow()).unwrap_or_default()
+    }
+
+    /// Restore overrides saved by [`HaCKS::phase_overrides_to_value`];
+    /// does nothing if the key is missing or malformed.
+    pub fn load_phase_overrides(&self, extra_settings: &HashMap<String, serde_json::Value>) {
+        if let Some(value) = extra_settings.get(PHASE_OVERRIDES_KEY) {
+            if let Ok(overrides) = serde_json::from_value(value.clone()) {
+                *self.phase_overrides.borrow_mut() = overrides;
+                *self.menu_dirty.borrow_mut() = true;
+            }
+        }
+    }
+}
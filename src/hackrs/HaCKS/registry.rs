@@ -126,7 +126,8 @@ impl crate::HaCKS {
     pub fn eject_module<T: HaCK + 'static>(&mut self) -> bool {
         let type_id = TypeId::of::<T>();
         if let Some(module_rc) = self.hacs.remove(&type_id) {
-            module_rc.borrow_mut().on_unload();
+            self.guard(&type_id, "on_unload", || module_rc.borrow_mut().on_unload());
+            self.health.borrow_mut().remove(&type_id);
             self.menu_dirty = true.into();
             
             // Unregister from state tracker
@@ -140,7 +141,8 @@ impl crate::HaCKS {
     
     pub fn eject_module_by_id(&mut self, type_id: TypeId) -> bool {
         if let Some(module_rc) = self.hacs.remove(&type_id) {
-            module_rc.borrow_mut().on_unload();
+            self.guard(&type_id, "on_unload", || module_rc.borrow_mut().on_unload());
+            self.health.borrow_mut().remove(&type_id);
             self.menu_dirty = true.into();
             true
         } else {
@@ -4,6 +4,8 @@ use crate::HaCKS;
 
 pub struct MenuCache {
     pub top_level: BTreeMap<String, Vec<(Vec<String>, TypeId)>>,
+    /// Keys of `top_level` in display order: groups holding a pinned module first
+    pub top_order: Vec<String>,
 }
 
 impl HaCKS {
@@ -11,7 +13,7 @@ impl HaCKS {
         let mut menu_tree: BTreeMap<Vec<String>, Vec<TypeId>> = BTreeMap::new();
         
         let type_ids: Vec<_> = self.hacs.keys().copied().collect();
-        let sorted = self.sort_by_weight(type_ids, |m_rc| m_rc.borrow().menu_weight()); // borrow for RefCell
+        let sorted = self.sort_for_menu(type_ids);
         
         for type_id in sorted {
             if let Some(module_rc) = self.hacs.get(&type_id) {
@@ -30,7 +32,18 @@ impl HaCKS {
             }
         }
         
-        MenuCache { top_level }
+        let has_pinned = |entries: &Vec<(Vec<String>, TypeId)>| {
+            entries.iter().any(|(_, id)| {
+                self.hacs.get(id).map(|m| m.borrow().metadata().menu_pinned).unwrap_or(false)
+            })
+        };
+        let (mut top_order, rest): (Vec<String>, Vec<String>) = top_level
+            .keys()
+            .cloned()
+            .partition(|name| has_pinned(&top_level[name]));
+        top_order.extend(rest);
+
+        MenuCache { top_level, top_order }
         
     }
         
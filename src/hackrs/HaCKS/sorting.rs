@@ -17,6 +17,43 @@ impl HaCKS {
         weighted.into_iter().map(|(id, _)| id).collect()
     }
     
+    /// Menu order: pinned modules first, then descending `menu_weight`, ties
+    /// broken by name so the order doesn't depend on `HashMap` layout.
+    ///
+    /// Modules that are currently mutably borrowed (e.g. the one whose
+    /// `update`/`render_*` is calling in through `&HaCKS`) are left out.
+    pub fn sort_for_menu(&self, type_ids: Vec<TypeId>) -> Vec<TypeId> {
+        let mut weighted: Vec<_> = type_ids
+            .into_iter()
+            .filter_map(|id| {
+                let m = self.hacs.get(&id)?.try_borrow().ok()?;
+                Some((id, m.metadata().menu_pinned, m.menu_weight(), m.name().to_string()))
+            })
+            .collect();
+
+        weighted.sort_by(|a, b| {
+            b.1.cmp(&a.1)
+                .then_with(|| b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal))
+                .then_with(|| a.3.cmp(&b.3))
+        });
+        weighted.into_iter().map(|(id, _, _, _)| id).collect()
+    }
+
+    /// Pin or unpin a module by name; returns false if no module has that
+    /// name or it is borrowed right now. Saved with the module's metadata.
+    pub fn set_menu_pinned(&self, name: &str, pinned: bool) -> bool {
+        let target = self
+            .hacs
+            .values()
+            .find(|m| m.try_borrow().is_ok_and(|m| m.name() == name));
+        let Some(mut module) = target.and_then(|m| m.try_borrow_mut().ok()) else {
+            return false;
+        };
+        module.metadata_mut().menu_pinned = pinned;
+        *self.menu_dirty.borrow_mut() = true;
+        true
+    }
+
     pub fn topological_sort_update(&self) -> Vec<TypeId> {
         let mut sorted = vec![];
         let mut visited = std::collections::HashSet::new();
@@ -50,4 +87,64 @@ impl HaCKS {
     }
     
 }
-    
\ No newline at end of file
+    
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::HaCMetadata;
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct Alpha {
+        hac_data: HaCMetadata,
+    }
+
+    impl HaCK for Alpha {
+        fn name(&self) -> &str {
+            "Alpha"
+        }
+
+        crate::impl_hac_boilerplate!(Alpha, hac_data);
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct Beta {
+        hac_data: HaCMetadata,
+    }
+
+    impl HaCK for Beta {
+        fn name(&self) -> &str {
+            "Beta"
+        }
+
+        crate::impl_hac_boilerplate!(Beta, hac_data);
+    }
+
+    fn menu_order(hacs: &HaCKS) -> Vec<TypeId> {
+        hacs.sort_for_menu(vec![TypeId::of::<Alpha>(), TypeId::of::<Beta>()])
+    }
+
+    #[test]
+    fn pinned_modules_sort_first() {
+        let mut hacs = HaCKS::new();
+        hacs.register(Alpha::default());
+        hacs.register(Beta::default());
+        assert_eq!(menu_order(&hacs), vec![TypeId::of::<Alpha>(), TypeId::of::<Beta>()]);
+
+        assert!(hacs.set_menu_pinned("Beta", true));
+        assert_eq!(menu_order(&hacs), vec![TypeId::of::<Beta>(), TypeId::of::<Alpha>()]);
+        assert!(!hacs.set_menu_pinned("Gamma", true));
+    }
+
+    #[test]
+    fn pinning_skips_borrowed_modules() {
+        let mut hacs = HaCKS::new();
+        hacs.register(Alpha::default());
+        hacs.register(Beta::default());
+
+        let _running = hacs.hacs[&TypeId::of::<Alpha>()].borrow_mut();
+        assert!(hacs.set_menu_pinned("Beta", true));
+        assert!(!hacs.set_menu_pinned("Alpha", true));
+    }
+}
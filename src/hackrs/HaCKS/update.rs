@@ -25,7 +25,7 @@ impl crate::HaCKS {
         }
         for type_id in &sorted {
             if let Some(module) = self.hacs.get(&type_id) {
-                module.borrow_mut().before_render(ui);
+                self.guard(type_id, "before_render", || module.borrow_mut().before_render(ui));
             }
         }
         if tracking_enabled {
@@ -43,68 +43,101 @@ impl crate::HaCKS {
         let sorted = self.topological_sort_update();
         for type_id in sorted {
             if let Some(module) = self.hacs.get(&type_id) {
-                module.borrow_mut().on_unload();
+                self.guard(&type_id, "on_unload", || module.borrow_mut().on_unload());
             }
         }
     }
 
+    /// Advances the shared clock itself unless `begin_frame` already did
+    /// this frame, so hosts that only call `update` still see time move.
     pub fn update(&self) {
+        if !self.frame_begun.replace(false) {
+            self.clock.borrow_mut().tick();
+        }
         self.sync_modules();
         self.tick(TickTarget::All);
     }
 
+    /// Whether `tick(target)` runs this module: it must be selected by
+    /// `target` and have the update phase enabled (module flag or host
+    /// override), so disabled modules are never woken. The flag defaults to
+    /// on, and `HaCMetadata::migrate` turns it on for saves that predate it.
+    fn should_tick(&self, type_id: &TypeId, target: &TickTarget) -> bool {
+        let Some(module_rc) = self.hacs.get(type_id) else {
+            return false;
+        };
+        let module = module_rc.borrow();
+        if !self.is_phase_enabled(&*module, crate::Phase::Update) {
+            return false;
+        }
+        match target {
+            TickTarget::All => true,
+            TickTarget::Module(id) => module.nac_type_id() == *id,
+            TickTarget::WeightRange(min, max) => (*min..=*max).contains(&module.update_weight()),
+        }
+    }
+
+    /// `update_interval_ms` throttle, measured on the shared clock
+    fn update_interval_elapsed(&self, type_id: &TypeId, now: f64) -> bool {
+        let Some(interval_ms) = self.hacs.get(type_id).and_then(|m| m.borrow().metadata().update_interval_ms) else {
+            return true;
+        };
+        match self.update_times.borrow().get(type_id) {
+            Some((last, _)) => (now - last) * 1000.0 >= interval_ms as f64,
+            None => true,
+        }
+    }
+
+    fn record_update_time(&self, type_id: &TypeId, now: f64) {
+        let mut times = self.update_times.borrow_mut();
+        let dt = times.get(type_id).map(|(last, _)| now - last).unwrap_or_else(|| self.delta_seconds());
+        times.insert(*type_id, (now, dt));
+    }
+
+    /// Seconds since this module's previous `update`, for use inside `update`.
+    ///
+    /// Equals `delta_seconds()` for modules updated every frame; for modules
+    /// throttled by `update_interval_ms` it is the time accumulated since they last ran.
+    pub fn update_delta_seconds(&self, type_id: TypeId) -> f64 {
+        self.update_times
+            .borrow()
+            .get(&type_id)
+            .map(|(_, dt)| *dt)
+            .unwrap_or_else(|| self.delta_seconds())
+    }
+
     pub fn tick(&self, target: TickTarget) {
-        let sorted = self.topological_sort_update();
+        let now = self.time_seconds();
+        // Decide once, so every pass agrees even after update timestamps move
+        let sorted: Vec<TypeId> = self.topological_sort_update()
+            .into_iter()
+            .filter(|id| self.should_tick(id, &target) && self.update_interval_elapsed(id, now))
+            .collect();
         let tracking_enabled = self.state_tracker.borrow().enabled;
-        for type_id in &sorted {
-            let should_update = {
-                let module = self.hacs.get(&type_id).unwrap();
-                let weight = module.borrow().update_weight();
-                match &target {
-                    TickTarget::All => true,
-                    TickTarget::Module(id) if module.borrow().nac_type_id() == *id => true,
-                    TickTarget::WeightRange(min, max) => (*min..=*max).contains(&weight),
-                    _ => false,
-                }
-            };
-            if should_update {
-                if tracking_enabled {
-                    if let Some(_module) = self.hacs.get(&type_id) {
-                        if let Some(tracker) = self.state_tracker.borrow_mut().get_tracker_mut(&type_id) {
-                            tracker.qued();
-                        }
-                    }
+        if tracking_enabled {
+            for type_id in &sorted {
+                if let Some(tracker) = self.state_tracker.borrow_mut().get_tracker_mut(type_id) {
+                    tracker.qued();
                 }
             }
         }
 
         for type_id in &sorted {
-            let should_update = {
-                let module = self.hacs.get(&type_id).unwrap();
-                let weight = module.borrow().update_weight();
-                match &target {
-                    TickTarget::All => true,
-                    TickTarget::Module(id) if module.borrow().nac_type_id() == *id => true,
-                    TickTarget::WeightRange(min, max) => (*min..=*max).contains(&weight),
-                    _ => false,
-                }
-            };
-
-            if should_update {
-                if let Some(module) = self.hacs.get(&type_id) {
-                    // Track update lifecycle
-                    if tracking_enabled {
-                        if let Some(tracker) = self.state_tracker.borrow_mut().get_tracker_mut(&type_id) {
-                            tracker.begin_update();
-                        }
+            if let Some(module) = self.hacs.get(type_id) {
+                self.record_update_time(type_id, now);
+
+                // Track update lifecycle
+                if tracking_enabled {
+                    if let Some(tracker) = self.state_tracker.borrow_mut().get_tracker_mut(type_id) {
+                        tracker.begin_update();
                     }
-                    
-                    module.borrow_mut().update(self);
-                    
-                    if tracking_enabled {
-                        if let Some(tracker) = self.state_tracker.borrow_mut().get_tracker_mut(&type_id) {
-                            tracker.end_update();
-                        }
+                }
+                
+                self.guard(type_id, "update", || module.borrow_mut().update(self));
+                
+                if tracking_enabled {
+                    if let Some(tracker) = self.state_tracker.borrow_mut().get_tracker_mut(type_id) {
+                        tracker.end_update();
                     }
                 }
             }
@@ -112,22 +145,8 @@ impl crate::HaCKS {
         
         if tracking_enabled {
             for type_id in &sorted {
-                let should_update = {
-                    let module = self.hacs.get(&type_id).unwrap();
-                    let weight = module.borrow().update_weight();
-                    match &target {
-                        TickTarget::All => true,
-                        TickTarget::Module(id) if module.borrow().nac_type_id() == *id => true,
-                        TickTarget::WeightRange(min, max) => (*min..=*max).contains(&weight),
-                        _ => false,
-                    }
-                };
-                if should_update {
-                    if let Some(_module) = self.hacs.get(&type_id) {
-                        if let Some(tracker) = self.state_tracker.borrow_mut().get_tracker_mut(&type_id) {
-                            tracker.stasis();
-                        }
-                    }
+                if let Some(tracker) = self.state_tracker.borrow_mut().get_tracker_mut(type_id) {
+                    tracker.stasis();
                 }
             }
         }
@@ -159,4 +178,109 @@ impl crate::HaCKS {
     //         module.borrow_mut().update(self);
     //     }
     // }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::{FrameClock, HaCK, HaCKS, HaCMetadata};
+
+    thread_local! {
+        static UPDATES: Cell<u32> = Cell::new(0);
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Throttled {
+        hac_data: HaCMetadata,
+    }
+
+    impl Default for Throttled {
+        fn default() -> Self {
+            Self {
+                hac_data: HaCMetadata::builder("Throttled").update_interval_ms(125).build(),
+            }
+        }
+    }
+
+    impl HaCK for Throttled {
+        fn name(&self) -> &str {
+            "Throttled"
+        }
+
+        fn update(&mut self, _hacs: &HaCKS) {
+            UPDATES.with(|c| c.set(c.get() + 1));
+        }
+
+        crate::impl_hac_boilerplate!(Throttled, hac_data);
+    }
+
+    thread_local! {
+        static DISABLED_UPDATES: Cell<u32> = Cell::new(0);
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Disabled {
+        hac_data: HaCMetadata,
+    }
+
+    impl HaCK for Disabled {
+        fn name(&self) -> &str {
+            "Disabled"
+        }
+
+        fn update(&mut self, _hacs: &HaCKS) {
+            DISABLED_UPDATES.with(|c| c.set(c.get() + 1));
+        }
+
+        crate::impl_hac_boilerplate!(Disabled, hac_data);
+    }
+
+    #[test]
+    fn update_disabled_module_is_not_invoked() {
+        let mut hacs = HaCKS::new();
+        hacs.register(Disabled {
+            hac_data: HaCMetadata::builder("Disabled").update_enabled(false).build(),
+        });
+        DISABLED_UPDATES.with(|c| c.set(0));
+
+        hacs.update();
+        hacs.update();
+        hacs.tick(TickTarget::Module(TypeId::of::<Disabled>()));
+        assert_eq!(DISABLED_UPDATES.with(|c| c.get()), 0);
+
+        hacs.set_phase_enabled("Disabled", crate::Phase::Update, true);
+        hacs.update();
+        assert_eq!(DISABLED_UPDATES.with(|c| c.get()), 1);
+    }
+
+    #[test]
+    fn update_interval_sets_cadence() {
+        let mut hacs = HaCKS::new();
+        hacs.register(Throttled::default());
+        hacs.set_clock(FrameClock::manual());
+        UPDATES.with(|c| c.set(0));
+
+        // 1/16 s frames against a 1/8 s interval: every other frame runs
+        for _ in 0..8 {
+            hacs.clock.borrow_mut().advance(0.0625);
+            hacs.tick(TickTarget::All);
+        }
+        assert_eq!(UPDATES.with(|c| c.get()), 4);
+        assert_eq!(hacs.update_delta_seconds(TypeId::of::<Throttled>()), 0.125);
+    }
+
+    #[test]
+    fn update_advances_clock_without_begin_frame() {
+        let hacs = HaCKS::new();
+        hacs.update();
+        hacs.update();
+        assert_eq!(hacs.frame_index(), 2);
+
+        hacs.begin_frame();
+        hacs.update();
+        assert_eq!(hacs.frame_index(), 3);
+    }
+}
@@ -0,0 +1,166 @@
+use std::time::Instant;
+
+/// Shared frame timeline, advanced once per frame by the host.
+///
+/// `time_seconds` is monotonic: it never goes backwards, even if the
+/// system clock does. In manual mode the clock only moves through
+/// [`FrameClock::advance`], which lets tests drive time explicitly.
+///
+/// The host controls `time_scale` (0.0 pauses). `time_seconds` and
+/// `delta_seconds` are scaled; `real_time_seconds` is not.
+#[derive(Debug, Clone)]
+pub struct FrameClock {
+    time: f64,
+    real_time: f64,
+    delta: f64,
+    time_scale: f32,
+    frame_index: u64,
+    last_real: Option<Instant>,
+    manual: bool,
+}
+
+impl FrameClock {
+    pub fn new() -> Self {
+        Self {
+            time: 0.0,
+            real_time: 0.0,
+            delta: 0.0,
+            time_scale: 1.0,
+            frame_index: 0,
+            last_real: None,
+            manual: false,
+        }
+    }
+
+    /// Clock that ignores wall time and only moves via `advance`
+    pub fn manual() -> Self {
+        Self { manual: true, ..Self::new() }
+    }
+
+    pub fn is_manual(&self) -> bool {
+        self.manual
+    }
+
+    /// Start a new frame using wall time. No-op in manual mode.
+    pub fn tick(&mut self) {
+        if self.manual {
+            return;
+        }
+        let now = Instant::now();
+        let dt = self
+            .last_real
+            .map(|last| now.saturating_duration_since(last).as_secs_f64())
+            .unwrap_or(0.0);
+        self.last_real = Some(now);
+        self.step(dt);
+    }
+
+    /// Start a new frame `dt` seconds after the previous one
+    pub fn advance(&mut self, dt: f64) {
+        self.step(dt);
+    }
+
+    fn step(&mut self, dt: f64) {
+        let dt = dt.max(0.0);
+        self.real_time += dt;
+        self.delta = dt * self.time_scale as f64;
+        self.time += self.delta;
+        self.frame_index += 1;
+    }
+
+    /// Scaled seconds since the clock started
+    pub fn time_seconds(&self) -> f64 {
+        self.time
+    }
+
+    /// Unscaled seconds since the clock started; keeps running while paused
+    pub fn real_time_seconds(&self) -> f64 {
+        self.real_time
+    }
+
+    /// Scaled seconds between the previous frame and this one
+    pub fn delta_seconds(&self) -> f64 {
+        self.delta
+    }
+
+    /// Multiplier applied to elapsed time (0.0 = paused, 1.0 = normal)
+    pub fn time_scale(&self) -> f32 {
+        self.time_scale
+    }
+
+    /// Takes effect from the next frame; negative values are clamped to 0
+    pub fn set_time_scale(&mut self, scale: f32) {
+        self.time_scale = scale.max(0.0);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.time_scale == 0.0
+    }
+
+    /// Frames started so far
+    pub fn frame_index(&self) -> u64 {
+        self.frame_index
+    }
+}
+
+impl Default for FrameClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manual_clock_only_moves_on_advance() {
+        let mut clock = FrameClock::manual();
+        clock.tick();
+        assert_eq!(clock.frame_index(), 0);
+        assert_eq!(clock.time_seconds(), 0.0);
+
+        clock.advance(0.5);
+        clock.advance(0.25);
+        assert_eq!(clock.frame_index(), 2);
+        assert_eq!(clock.time_seconds(), 0.75);
+        assert_eq!(clock.delta_seconds(), 0.25);
+    }
+
+    #[test]
+    fn time_scale_scales_time_but_not_real_time() {
+        let mut clock = FrameClock::manual();
+        clock.set_time_scale(0.5);
+        clock.advance(1.0);
+        assert_eq!(clock.time_seconds(), 0.5);
+        assert_eq!(clock.delta_seconds(), 0.5);
+        assert_eq!(clock.real_time_seconds(), 1.0);
+
+        clock.set_time_scale(0.0);
+        assert!(clock.is_paused());
+        clock.advance(1.0);
+        assert_eq!(clock.time_seconds(), 0.5);
+        assert_eq!(clock.real_time_seconds(), 2.0);
+    }
+
+    #[test]
+    fn never_goes_backwards() {
+        let mut clock = FrameClock::manual();
+        clock.advance(1.0);
+        clock.advance(-5.0);
+        assert_eq!(clock.time_seconds(), 1.0);
+        assert_eq!(clock.delta_seconds(), 0.0);
+
+        clock.set_time_scale(-2.0);
+        assert_eq!(clock.time_scale(), 0.0);
+    }
+
+    #[test]
+    fn wall_clock_ticks_count_frames() {
+        let mut clock = FrameClock::new();
+        clock.tick();
+        clock.tick();
+        assert_eq!(clock.frame_index(), 2);
+        assert!(clock.time_seconds() >= 0.0);
+    }
+}
@@ -2,7 +2,7 @@ use std::any::{Any, TypeId};
 use serde::Deserialize;
 use erased_serde::Serialize as ErasedSerialize;
 use imgui::{Ui,DrawListMut};
-use crate::{HaCKS, HaCMetadata, metadata::HotkeyBinding};
+use crate::{HaCKS, HaCMetadata, gui::Camera2D, metadata::HotkeyBinding, structview::FieldMeta};
 
 #[allow(unused)]
 pub trait HaCK: ErasedSerialize + Send + 'static {
@@ -15,6 +15,16 @@ pub trait HaCK: ErasedSerialize + Send + 'static {
         draw_fg: &mut DrawListMut,
         draw_bg: &mut DrawListMut
     ) {}
+    /// `render_draw` into lists the host is compositing (minimap, thumbnail)
+    /// rather than the overlay. Modules drawing world-space content should
+    /// override this and project through `camera`; the default ignores it.
+    fn render_draw_with_camera(&mut self, ui: &Ui,
+        draw_fg: &mut DrawListMut,
+        draw_bg: &mut DrawListMut,
+        camera: &Camera2D,
+    ) {
+        self.render_draw(ui, draw_fg, draw_bg)
+    }
     fn render_menu(&mut self, ui: &Ui) {}
     fn render_window(&mut self, ui: &Ui) {}
     fn is_menu_enabled(&self) -> bool;
@@ -39,7 +49,14 @@ pub trait HaCK: ErasedSerialize + Send + 'static {
     fn menu_path(&self) -> Vec<&str> { vec![self.name()] }
     fn on_unload(&mut self) { }
     fn on_load(&mut self) { }
+    /// Called when the user closes the module's window (title-bar X). The
+    /// module stays loaded, unlike `on_unload`; stop window-only work here.
+    fn on_window_closed(&mut self) {}
     fn before_render(&mut self,ui: &Ui) {}
+    /// Called once per frame before `update`; reset per-frame state here
+    fn on_frame_begin(&mut self) {}
+    /// Called once per frame after all render passes; flush per-frame work here
+    fn on_frame_end(&mut self) {}
     fn init(&mut self) {}
     fn hotkey_bindings(&self) -> &[HotkeyBinding] {
         &self.metadata().hotkeys
@@ -59,10 +76,95 @@ pub trait HaCK: ErasedSerialize + Send + 'static {
         }
         Ok(json)
     }
+    /// Run a named action on behalf of the host or a script.
+    ///
+    /// Command names are local to the module (`"reload"`, `"spawn_rolling"`);
+    /// `args` is JSON whose shape each command defines. Unhandled names must
+    /// return `CommandError::UnknownCommand`.
+    fn execute_command(&mut self, cmd: &str, args: &str) -> Result<String, CommandError> {
+        Err(CommandError::UnknownCommand(cmd.to_string()))
+    }
+    /// Read-only snapshot of transient runtime state (counts, progress, positions)
+    /// for debugging and scripting. Unlike `to_json` it need not round-trip;
+    /// `Value::Null` means nothing to report.
+    fn describe_state(&self) -> serde_json::Value { serde_json::Value::Null }
+    /// Field layout shown in the debug viewer; forward `FieldInfo::get_field_info`
+    /// here from modules deriving `DeriveFieldInfo`
+    fn debug_fields(&self) -> Vec<FieldMeta> { vec![] }
+    /// Optional features this module implements, so the host can skip the rest.
+    ///
+    /// Defaults to `ALL`, so modules that don't override this get every call.
+    /// Overriding it is an opt-out: frame hooks, `on_hotkey` and `render_draw`
+    /// are skipped unless `FRAME_HOOKS`, `HOTKEYS` or `DRAW` is listed.
+    fn capabilities(&self) -> HaCKCapabilities { HaCKCapabilities::ALL }
 
     
 }
 
+/// Failure from [`HaCK::execute_command`] / `HaCKS::execute_command`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommandError {
+    UnknownModule(String),
+    UnknownCommand(String),
+    /// Arguments didn't parse or didn't fit the command
+    InvalidArgs(String),
+    Failed(String),
+}
+
+impl std::fmt::Display for CommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommandError::UnknownModule(name) => write!(f, "unknown module: {}", name),
+            CommandError::UnknownCommand(cmd) => write!(f, "unknown command: {}", cmd),
+            CommandError::InvalidArgs(msg) => write!(f, "invalid arguments: {}", msg),
+            CommandError::Failed(msg) => write!(f, "command failed: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+/// Bit set of optional [`HaCK`] features a module declares.
+///
+/// Bits not listed here are reserved; hosts must ignore unknown bits so
+/// modules built against a newer version keep working.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HaCKCapabilities(pub u32);
+
+impl HaCKCapabilities {
+    pub const NONE: Self = Self(0);
+    /// Every feature, including bits added later; the default for modules that don't declare
+    pub const ALL: Self = Self(!0);
+    /// Implements `on_frame_begin` / `on_frame_end`
+    pub const FRAME_HOOKS: Self = Self(1 << 0);
+    /// Handles `on_hotkey`
+    pub const HOTKEYS: Self = Self(1 << 1);
+    /// Draws overlays in `render_draw`
+    pub const DRAW: Self = Self(1 << 2);
+
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+/// Same as an undeclared module: [`HaCKCapabilities::ALL`]
+impl Default for HaCKCapabilities {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl std::ops::BitOr for HaCKCapabilities {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
 #[allow(unused)]
 pub trait ModuleSettings: HaCK + Sized + for<'de> Deserialize<'de> {
     fn settings_key() -> &'static str;
@@ -70,3 +172,15 @@ pub trait ModuleSettings: HaCK + Sized + for<'de> Deserialize<'de> {
 
 use erased_serde::serialize_trait_object;
 serialize_trait_object!(HaCK);
+
+#[cfg(test)]
+mod tests {
+    use super::HaCKCapabilities;
+
+    #[test]
+    fn default_capabilities_match_undeclared_modules() {
+        assert_eq!(HaCKCapabilities::default(), HaCKCapabilities::ALL);
+        assert!(HaCKCapabilities::default().contains(HaCKCapabilities::DRAW | HaCKCapabilities::HOTKEYS));
+        assert!(!HaCKCapabilities::NONE.contains(HaCKCapabilities::FRAME_HOOKS));
+    }
+}
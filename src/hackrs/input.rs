@@ -0,0 +1,90 @@
+use std::time::{Duration, Instant};
+
+/// Real-time rate limiter for repeatable actions (spawning, firing, toggling).
+///
+/// Unlike `frame_count % N` throttles this doesn't depend on frame rate.
+/// ```ignore
+/// let mut spawn = Debounce::new(Duration::from_millis(150));
+/// if key_down && spawn.try_fire(Instant::now()) {
+///     spawn_enemy();
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Debounce {
+    pub last: Option<Instant>,
+    pub interval: Duration,
+}
+
+impl Debounce {
+    pub fn new(interval: Duration) -> Self {
+        Self { last: None, interval }
+    }
+
+    pub fn from_millis(ms: u64) -> Self {
+        Self::new(Duration::from_millis(ms))
+    }
+
+    /// True if at least `interval` has passed since the last fire at `now`
+    pub fn ready(&self, now: Instant) -> bool {
+        match self.last {
+            None => true,
+            Some(last) => now.saturating_duration_since(last) >= self.interval,
+        }
+    }
+
+    /// Fire if ready, recording `now` as the last fire time
+    pub fn try_fire(&mut self, now: Instant) -> bool {
+        if self.ready(now) {
+            self.last = Some(now);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Make the next `try_fire` succeed immediately
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+}
+
+impl Default for Debounce {
+    fn default() -> Self {
+        Self::from_millis(200)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fires_once_per_interval() {
+        let start = Instant::now();
+        let mut debounce = Debounce::from_millis(100);
+
+        assert!(debounce.try_fire(start));
+        assert!(!debounce.try_fire(start + Duration::from_millis(50)));
+        assert!(!debounce.try_fire(start + Duration::from_millis(99)));
+        assert!(debounce.try_fire(start + Duration::from_millis(100)));
+        // The interval restarts from the successful fire
+        assert!(!debounce.ready(start + Duration::from_millis(150)));
+    }
+
+    #[test]
+    fn reset_allows_immediate_fire() {
+        let start = Instant::now();
+        let mut debounce = Debounce::default();
+        assert!(debounce.try_fire(start));
+        debounce.reset();
+        assert!(debounce.try_fire(start));
+    }
+
+    #[test]
+    fn earlier_instant_is_not_ready() {
+        let start = Instant::now() + Duration::from_secs(1);
+        let mut debounce = Debounce::from_millis(100);
+        assert!(debounce.try_fire(start));
+        assert!(!debounce.ready(start - Duration::from_millis(500)));
+    }
+}
@@ -63,8 +63,14 @@ impl HotkeyBinding {
     }
 }
 
+/// Version written by this crate; see [`HaCMetadata::migrate`].
+pub const METADATA_VERSION: u32 = 1;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct HaCMetadata {
+    /// Missing in files saved before versioning, which reads as 0
+    #[serde(default)]
+    pub metadata_version: u32,
     pub name: Cow<'static, str>,
     pub description: Cow<'static, str>,
     pub category: Cow<'static, str>,
@@ -78,6 +84,7 @@ pub struct HaCMetadata {
     pub is_menu_enabled: bool,
     pub is_window_enabled: bool,
     pub is_render_enabled: bool,
+    #[serde(default = "default_true")]
     pub is_update_enabled: bool,
     #[serde(default = "default_window_pos")]
     pub window_pos: [f32; 2],
@@ -86,11 +93,26 @@ pub struct HaCMetadata {
     pub auto_resize_window: bool,
     #[serde(default)]
     pub access_control: AccessControl,
+    /// Module draws into the background list in `render_draw`
+    #[serde(default = "default_true")]
+    pub draws_background: bool,
+    /// Module draws into the foreground list in `render_draw`
+    #[serde(default = "default_true")]
+    pub draws_foreground: bool,
+    /// Minimum milliseconds between `update` calls, on the shared (scaled)
+    /// clock; `None` updates every tick. The clock advances in `HaCKS::update`
+    /// or `begin_frame`; a host driving `tick` directly must advance it itself.
+    #[serde(default)]
+    pub update_interval_ms: Option<u64>,
+    /// Listed before unpinned modules in menus, regardless of weight
+    #[serde(default)]
+    pub menu_pinned: bool,
 }
 
 impl Default for HaCMetadata {
     fn default() -> Self {
         Self {
+            metadata_version: METADATA_VERSION,
             name: Cow::Borrowed("unknown"),
             description: Cow::Borrowed("unknown"),
             category: Cow::Borrowed("unknown"),
@@ -103,14 +125,150 @@ impl Default for HaCMetadata {
             is_menu_enabled: true,
             is_window_enabled: false,
             is_render_enabled: false,
-            is_update_enabled: false,
+            is_update_enabled: true,
             auto_resize_window: true,
             window_pos: default_window_pos(),
             window_size: default_window_size(),
-            access_control: AccessControl::new(AccessLevel::ReadWrite)
+            access_control: AccessControl::new(AccessLevel::ReadWrite),
+            draws_background: true,
+            draws_foreground: true,
+            update_interval_ms: None,
+            menu_pinned: false,
         }
     }
 }
 
 pub const fn default_window_pos() -> [f32; 2] { [0.0, 0.0] }
-pub const fn default_window_size() -> [f32; 2] { [0.0, 0.0] }
\ No newline at end of file
+pub const fn default_window_size() -> [f32; 2] { [0.0, 0.0] }
+pub const fn default_true() -> bool { true }
+
+impl HaCMetadata {
+    /// Default metadata carrying the given name.
+    pub fn with_defaults(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Bring metadata loaded from an older save up to [`METADATA_VERSION`].
+    ///
+    /// Before version 1 `is_update_enabled` was never consulted, so saves
+    /// hold `false` for modules that were updated every tick; those are
+    /// switched on. `load_all_settings` calls this; hosts with their own
+    /// loading path should call it after deserializing.
+    pub fn migrate(&mut self) {
+        if self.metadata_version < 1 {
+            self.is_update_enabled = true;
+        }
+        self.metadata_version = METADATA_VERSION;
+    }
+
+    /// Start building metadata from [`HaCMetadata::with_defaults`].
+    ///
+    /// # Example
+    /// ```
+    /// use hackers::HaCMetadata;
+    ///
+    /// let meta = HaCMetadata::builder("ESP")
+    ///     .description("Draws boxes over entities")
+    ///     .category("Visuals")
+    ///     .render_enabled(true)
+    ///     .build();
+    /// ```
+    pub fn builder(name: impl Into<Cow<'static, str>>) -> HaCMetadataBuilder {
+        HaCMetadataBuilder {
+            metadata: Self::with_defaults(name),
+        }
+    }
+}
+
+/// Fluent builder for [`HaCMetadata`]; unset fields keep their defaults.
+pub struct HaCMetadataBuilder {
+    metadata: HaCMetadata,
+}
+
+impl HaCMetadataBuilder {
+    pub fn description(mut self, description: impl Into<Cow<'static, str>>) -> Self {
+        self.metadata.description = description.into();
+        self
+    }
+
+    pub fn category(mut self, category: impl Into<Cow<'static, str>>) -> Self {
+        self.metadata.category = category.into();
+        self
+    }
+
+    pub fn hotkey(mut self, binding: HotkeyBinding) -> Self {
+        self.metadata.hotkeys.push(binding);
+        self
+    }
+
+    pub fn menu_weight(mut self, weight: f32) -> Self { self.metadata.menu_weight = weight; self }
+    pub fn window_weight(mut self, weight: f32) -> Self { self.metadata.window_weight = weight; self }
+    pub fn draw_weight(mut self, weight: f32) -> Self { self.metadata.draw_weight = weight; self }
+    pub fn update_weight(mut self, weight: f32) -> Self { self.metadata.update_weight = weight; self }
+    pub fn update_interval_ms(mut self, ms: u64) -> Self { self.metadata.update_interval_ms = Some(ms); self }
+
+    pub fn visible_in_gui(mut self, visible: bool) -> Self { self.metadata.visible_in_gui = visible; self }
+    pub fn menu_enabled(mut self, enabled: bool) -> Self { self.metadata.is_menu_enabled = enabled; self }
+    pub fn window_enabled(mut self, enabled: bool) -> Self { self.metadata.is_window_enabled = enabled; self }
+    pub fn render_enabled(mut self, enabled: bool) -> Self { self.metadata.is_render_enabled = enabled; self }
+    pub fn update_enabled(mut self, enabled: bool) -> Self { self.metadata.is_update_enabled = enabled; self }
+
+    pub fn window_pos(mut self, pos: [f32; 2]) -> Self { self.metadata.window_pos = pos; self }
+
+    /// Fixed window size; also turns off auto-resize so the size is used.
+    pub fn window_size(mut self, size: [f32; 2]) -> Self {
+        self.metadata.window_size = size;
+        self.metadata.auto_resize_window = false;
+        self
+    }
+
+    pub fn auto_resize_window(mut self, auto_resize: bool) -> Self {
+        self.metadata.auto_resize_window = auto_resize;
+        self
+    }
+
+    /// Declare which `render_draw` layers the module uses; with neither set it is skipped.
+    pub fn draw_layers(mut self, background: bool, foreground: bool) -> Self {
+        self.metadata.draws_background = background;
+        self.metadata.draws_foreground = foreground;
+        self
+    }
+
+    pub fn access_control(mut self, access_control: AccessControl) -> Self {
+        self.metadata.access_control = access_control;
+        self
+    }
+
+    pub fn build(self) -> HaCMetadata {
+        self.metadata
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migrate_enables_update_for_unversioned_saves() {
+        let mut value = serde_json::to_value(HaCMetadata::default()).unwrap();
+        let obj = value.as_object_mut().unwrap();
+        obj.remove("metadata_version");
+        obj.insert("is_update_enabled".into(), serde_json::Value::Bool(false));
+
+        let mut metadata: HaCMetadata = serde_json::from_value(value).unwrap();
+        assert_eq!(metadata.metadata_version, 0);
+        metadata.migrate();
+        assert!(metadata.is_update_enabled);
+        assert_eq!(metadata.metadata_version, METADATA_VERSION);
+    }
+
+    #[test]
+    fn migrate_keeps_current_saves() {
+        let mut metadata = HaCMetadata::builder("m").update_enabled(false).build();
+        metadata.migrate();
+        assert!(!metadata.is_update_enabled);
+    }
+}
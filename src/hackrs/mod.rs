@@ -6,9 +6,16 @@ pub mod access;
 pub mod access_methods;
 pub mod sync;
 pub mod runtime_sync;
+pub mod input;
+pub mod rng;
+pub mod clock;
 
 pub use access::{AccessLevel, AccessControl, AccessManager, AccessToken};
-pub use metadata::HaCMetadata;
-pub use hack::HaCK;
+pub use metadata::{HaCMetadata, HaCMetadataBuilder, METADATA_VERSION};
+pub use hack::{CommandError, HaCK, HaCKCapabilities};
 pub use sync::*;
-pub use runtime_sync::*;
\ No newline at end of file
+pub use runtime_sync::*;
+pub use input::Debounce;
+pub use rng::Rng;
+pub use clock::FrameClock;
+pub use HaCKS::{ModuleHealth, Phase, DEFAULT_MAX_MODULE_FAILURES, PHASE_OVERRIDES_KEY};
\ No newline at end of file
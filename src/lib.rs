@@ -13,10 +13,14 @@ pub use HaCKS::HaCKS;
 pub use HaCK;
 pub use state_tracker::*;
 pub use serde_json;
+#[cfg(feature = "gui")]
+pub use imgui;
 pub use debug::*;
 
-pub use hackers_derive::DeriveFieldInfo;
+pub use hackers_derive::{DeriveFieldInfo, ConfigUi};
+#[cfg(feature = "gui")]
+pub use structview::{FieldFormatterRegistry, FieldInfo, FieldMeta, StructViewer};
 #[cfg(feature = "gui")]
-pub use structview::{FieldInfo, FieldMeta, StructViewer};
+pub use gui::ConfigUi;
 
 
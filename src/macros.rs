@@ -102,12 +102,48 @@ macro_rules! impl_hac_boilerplate {
 }
 
 
+// Per-entry save/load for `declare_and_register_hacs!`: plain serde, or
+// `=> versioned` for modules implementing `Migratable`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __hac_settings_to_value {
+    ($module_path:path, $value:expr) => {
+        serde_json::to_value($value)
+    };
+    ($module_path:path, $value:expr, versioned) => {
+        $crate::hackrs::HaCKS::persistence::to_versioned_value::<$module_path>($value)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __hac_settings_from_value {
+    ($module_path:path, $value:expr) => {
+        serde_json::from_value::<$module_path>($value)
+    };
+    ($module_path:path, $value:expr, versioned) => {
+        $crate::hackrs::HaCKS::persistence::from_versioned_value::<$module_path>($value)
+    };
+}
+
+/// Declare module submodules and generate `create_modules`,
+/// `save_all_settings` and `load_all_settings[_with_errors]` for them.
+///
+/// Append `=> versioned` to an entry whose type implements
+/// `persistence::Migratable`; its settings are then saved with a version
+/// stamp and run through `migrate` on load instead of failing to parse.
+/// ```ignore
+/// declare_and_register_hacs! {
+///     esp => esp::Esp, "esp",
+///     radar => radar::Radar, "radar" => versioned,
+/// }
+/// ```
 #[macro_export]
 macro_rules! declare_and_register_hacs {
     (
         $(
             $(#[$attr:meta])*
-            $mod_name:ident => $module_path:path, $key:literal
+            $mod_name:ident => $module_path:path, $key:literal $(=> $versioned:ident)?
         ),* $(,)?
     ) => {
         // Import necessary types at the top level
@@ -148,7 +184,7 @@ macro_rules! declare_and_register_hacs {
                         // borrow() returns Ref directly, not Result
                         let hac_ref = hac_rc.borrow();
                         if let Some(m) = hac_ref.as_any().downcast_ref::<$module_path>() {
-                            if let Ok(value) = serde_json::to_value(m) {
+                            if let Ok(value) = $crate::__hac_settings_to_value!($module_path, m $(, $versioned)?) {
                                 settings.insert($key.to_string(), value);
                             }
                         }
@@ -162,14 +198,33 @@ macro_rules! declare_and_register_hacs {
         // Load settings - Updated for Rc<RefCell<_>>
         pub fn load_all_settings(
             settings: &HashMap<String, serde_json::Value>
+        ) -> Vec<Rc<RefCell<dyn $crate::HaCK>>> {
+            load_all_settings_with_errors(settings, &mut Vec::new())
+        }
+
+        // Same, appending a message for each module whose saved settings
+        // could not be used (it falls back to defaults)
+        pub fn load_all_settings_with_errors(
+            settings: &HashMap<String, serde_json::Value>,
+            errors: &mut Vec<String>,
         ) -> Vec<Rc<RefCell<dyn $crate::HaCK>>> {
             use $crate::HaCK as _;
             vec![
                 $(
                     Rc::new(RefCell::new({
-                        let mut module = settings.get($key)
-                            .and_then(|v| serde_json::from_value::<$module_path>(v.clone()).ok())
-                            .unwrap_or_default();
+                        let mut module = match settings.get($key) {
+                            Some(v) => match $crate::__hac_settings_from_value!($module_path, v.clone() $(, $versioned)?) {
+                                Ok(module) => module,
+                                Err(e) => {
+                                    let message = format!("{}: failed to load settings, using defaults: {}", $key, e);
+                                    $crate::debug::LogOnce::error("persistence", message.clone());
+                                    errors.push(message);
+                                    <$module_path>::default()
+                                }
+                            },
+                            None => <$module_path>::default(),
+                        };
+                        module.metadata_mut().migrate();
                         module.post_load_init();
                         module
                     })) as Rc<RefCell<dyn $crate::HaCK>>
@@ -583,8 +583,7 @@ impl PhaseTimingVisualizer {
 
     /// Convert RGB to imgui color u32
     fn rgb_to_u32(r: u8, g: u8, b: u8) -> u32 {
-        let a = 255u8;
-        ((a as u32) << 24) | ((b as u32) << 16) | ((g as u32) << 8) | (r as u32)
+        crate::gui::color::pack_rgba(r, g, b, 255)
     }
 
     /// Render compact overview showing time between key phases
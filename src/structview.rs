@@ -2,7 +2,9 @@ pub use hackers_derive::DeriveFieldInfo;
 
 use imgui::{self, TreeNodeFlags, Ui};
 use strum::{EnumIter, IntoEnumIterator, Display};
+use std::collections::HashMap;
 use std::mem;
+use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub struct FieldMeta {
@@ -17,6 +19,66 @@ pub trait FieldInfo: std::any::Any {
     fn get_field_info(&self) -> Vec<FieldMeta>;
 }
 
+type FieldFormatter = Box<dyn Fn(&FieldMeta) -> String + Send + Sync>;
+
+/// Runtime formatters that override a field's derived `interpret` string,
+/// e.g. to show a `u32` as an enum name or a timestamp as a date.
+///
+/// Field-name formatters win over type-name ones. Build it once at startup
+/// and hand it to viewers with [`StructViewer::with_formatters`].
+///
+/// Array fields reach the viewer as one `FieldMeta` per element, named
+/// `pos.0`, `pos.1`, ... with the element's `type_name`. A formatter
+/// registered for `pos` therefore runs once per element (read the index from
+/// `field.name`), and one registered for `f32` also covers `[f32; N]` elements.
+#[derive(Default)]
+pub struct FieldFormatterRegistry {
+    by_field: HashMap<String, FieldFormatter>,
+    by_type: HashMap<String, FieldFormatter>,
+}
+
+impl FieldFormatterRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Format every field named `field_name`, or every element of the array field with that name
+    pub fn register_field<F>(&mut self, field_name: impl Into<String>, f: F)
+    where
+        F: Fn(&FieldMeta) -> String + Send + Sync + 'static,
+    {
+        self.by_field.insert(field_name.into(), Box::new(f));
+    }
+
+    /// Format every field whose `type_name` matches, as written in the struct
+    /// (`u32`, `MyEnum`); for arrays this is the element type
+    pub fn register_type<F>(&mut self, type_name: impl Into<String>, f: F)
+    where
+        F: Fn(&FieldMeta) -> String + Send + Sync + 'static,
+    {
+        self.by_type.insert(type_name.into(), Box::new(f));
+    }
+
+    /// Lookup order: exact field name, array field name (`pos` for `pos.0`), type name.
+    pub fn format(&self, field: &FieldMeta) -> Option<String> {
+        self.by_field
+            .get(&field.name)
+            .or_else(|| array_parent(&field.name).and_then(|parent| self.by_field.get(parent)))
+            .or_else(|| self.by_type.get(field.type_name))
+            .map(|f| f(field))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_field.is_empty() && self.by_type.is_empty()
+    }
+}
+
+/// `pos` for an expanded array element named `pos.3`
+fn array_parent(name: &str) -> Option<&str> {
+    let (parent, index) = name.rsplit_once('.')?;
+    (!index.is_empty() && index.bytes().all(|b| b.is_ascii_digit())).then_some(parent)
+}
+
 // ============================================================================
 // Trait Definitions
 // ============================================================================
@@ -411,6 +473,8 @@ impl HighlightRuleManager {
 pub struct StructViewer<T: FieldInfo> {
     config: StructViewerConfig,
     rule_manager: HighlightRuleManager,
+    filter: String,
+    formatters: Arc<FieldFormatterRegistry>,
     _phantom: std::marker::PhantomData<T>,
 }
 
@@ -419,6 +483,8 @@ impl<T: FieldInfo> Clone for StructViewer<T> {
         Self {
             config: self.config.clone(),
             rule_manager: self.rule_manager.clone(),
+            filter: self.filter.clone(),
+            formatters: Arc::clone(&self.formatters),
             _phantom: std::marker::PhantomData,
         }
     }
@@ -429,6 +495,8 @@ impl<T: FieldInfo> Default for StructViewer<T> {
         StructViewer {
             config: StructViewerConfig::default(),
             rule_manager: HighlightRuleManager::new(),
+            filter: String::new(),
+            formatters: Arc::new(FieldFormatterRegistry::new()),
             _phantom: std::marker::PhantomData,
         }
     }
@@ -439,6 +507,16 @@ impl<T: FieldInfo> StructViewer<T> {
         Self::default()
     }
 
+    /// Use `formatters` for field values before falling back to the derived ones
+    pub fn with_formatters(mut self, formatters: Arc<FieldFormatterRegistry>) -> Self {
+        self.formatters = formatters;
+        self
+    }
+
+    fn interpret_field(&self, field: &FieldMeta) -> Option<String> {
+        self.formatters.format(field).or_else(|| field.interpret.clone())
+    }
+
     fn byte_display_mode(&mut self, ui: &Ui) {
         ui.text("Byte Display Mode:");
         if ui.radio_button("Hex", &mut self.config.byte_display_mode, ByteDisplayMode::Hex) {}
@@ -476,21 +554,42 @@ impl<T: FieldInfo> StructViewer<T> {
         let type_name = std::any::type_name::<T>()
             .rsplit("::").next().unwrap_or("Unknown");
         ui.text_colored(self.config.struct_name_color, type_name);
+
+        ui.set_next_item_width(200.0);
+        ui.input_text("Filter##struct_viewer_filter", &mut self.filter)
+            .hint("field name")
+            .build();
         
         let ptr = value as *const T as *const u8;
         let bytes = unsafe { std::slice::from_raw_parts(ptr, mem::size_of::<T>()) };
         let fields = value.get_field_info();
         
-        self.display_all_fields(ui, bytes, &fields);
+        self.display_filtered(ui, bytes, &fields, &self.filter);
     }
     
     pub fn display_all_fields(&self, ui: &imgui::Ui, bytes: &[u8], fields: &[FieldMeta]) {
+        self.display_filtered(ui, bytes, fields, "");
+    }
+
+    /// Like [`StructViewer::display_all_fields`], but only fields whose name
+    /// contains `query` (case-insensitive). An empty query shows everything.
+    pub fn display_filtered(&self, ui: &imgui::Ui, bytes: &[u8], fields: &[FieldMeta], query: &str) {
         let bytes_per_row = self.config.bytes_per_row.max(1);
+        let query = query.trim();
+        let fields: Vec<&FieldMeta> = fields
+            .iter()
+            .filter(|f| find_ignore_case(&f.name, query).is_some())
+            .collect();
+
+        if fields.is_empty() {
+            ui.text_disabled("No matching fields");
+            return;
+        }
     
         // First pass: determine max hex width across all fields
         let mut max_hex_width = 0;
     
-        for field in fields {
+        for field in &fields {
             let FieldMeta { offset, size, .. } = field;
     
             if *offset >= bytes.len() { continue; }
@@ -511,37 +610,103 @@ impl<T: FieldInfo> StructViewer<T> {
             }
         }
     
-        // Second pass: render with uniform hex width
+        // Second pass: render with uniform hex width, grouping `prefix.rest` names
+        if !fields.iter().any(|f| f.name.contains('.')) {
+            for field in fields {
+                self.render_field(ui, bytes, field, bytes_per_row, max_hex_width, query);
+            }
+            return;
+        }
+
+        let mut groups: Vec<(Option<&str>, Vec<&FieldMeta>)> = Vec::new();
         for field in fields {
-            let FieldMeta { name, offset, size, type_name, interpret } = field;
-    
-            ui.tree_node_config(name)
-                .flags(TreeNodeFlags::DEFAULT_OPEN)
-                .build(|| {
-                    ui.text_colored(self.config.field_name_color, format!(
-                        "Offset: {:#06X}, Size: {} bytes, Type: {}",
-                        offset, size, type_name,
-                    ));
-                    ui.spacing();
-    
-                    if *offset >= bytes.len() {
-                        ui.text_colored([1.0, 0.0, 0.0, 1.0], "Offset out of range");
-                        return;
+            let prefix = field.name.split_once('.').map(|(p, _)| p);
+            match groups.iter_mut().find(|(p, _)| prefix.is_some() && *p == prefix) {
+                Some((_, members)) => members.push(field),
+                None => groups.push((prefix, vec![field])),
+            }
+        }
+
+        for (prefix, members) in groups {
+            match prefix {
+                Some(prefix) => {
+                    let _id = ui.push_id(prefix);
+                    if ui.collapsing_header(prefix, TreeNodeFlags::DEFAULT_OPEN) {
+                        ui.indent();
+                        for field in members {
+                            self.render_field(ui, bytes, field, bytes_per_row, max_hex_width, query);
+                        }
+                        ui.unindent();
                     }
-    
-                    let avail = bytes.len() - offset;
-                    let slice = &bytes[*offset..(*offset + size.min(&avail))];
-    
-                    self.draw_memory_table(
-                        ui,
-                        slice,
-                        bytes_per_row,
-                        Some(type_name),
-                        interpret.clone(),
-                        &self.config.highlight_rules,
-                        max_hex_width,
-                    );
-                });
+                }
+                None => {
+                    for field in members {
+                        self.render_field(ui, bytes, field, bytes_per_row, max_hex_width, query);
+                    }
+                }
+            }
+        }
+    }
+
+    fn render_field(
+        &self,
+        ui: &imgui::Ui,
+        bytes: &[u8],
+        field: &FieldMeta,
+        bytes_per_row: usize,
+        max_hex_width: usize,
+        query: &str,
+    ) {
+        let FieldMeta { name, offset, size, type_name, .. } = field;
+        let interpret = self.interpret_field(field);
+        let _id = ui.push_id(name.as_str());
+
+        let label = if query.is_empty() { name.clone() } else { format!("##{}", name) };
+        let node = ui.tree_node_config(label).flags(TreeNodeFlags::DEFAULT_OPEN).push();
+        if ui.is_item_clicked_with_button(imgui::MouseButton::Right) {
+            ui.open_popup("##field_ctx");
+        }
+        if !query.is_empty() {
+            ui.same_line();
+            text_with_highlight(ui, name, query, [1.0, 0.9, 0.2, 1.0]);
+        }
+
+        if let Some(_popup) = ui.begin_popup("##field_ctx") {
+            if ui.menu_item("Copy value") {
+                ui.set_clipboard_text(field_value_text(bytes, field, interpret.as_deref()));
+            }
+            if ui.menu_item("Copy offset (hex)") {
+                ui.set_clipboard_text(format!("{:#X}", offset));
+            }
+            if ui.menu_item("Copy name") {
+                ui.set_clipboard_text(name);
+            }
+        }
+
+        if let Some(_node) = node {
+            ui.text_colored(self.config.field_name_color, format!(
+                "Offset: {:#06X}, Size: {} bytes, Type: {}",
+                offset, size, type_name,
+            ));
+            ui.spacing();
+
+            if *offset >= bytes.len() {
+                ui.text_colored([1.0, 0.0, 0.0, 1.0], "Offset out of range");
+                return;
+            }
+
+            let avail = bytes.len() - offset;
+            let slice = &bytes[*offset..(*offset + size.min(&avail))];
+
+            self.draw_memory_table(
+                ui,
+                slice,
+                bytes_per_row,
+                Some(type_name),
+                interpret,
+                &self.config.highlight_rules,
+                max_hex_width,
+            );
         }
     }
     
@@ -643,6 +808,57 @@ impl<T: FieldInfo> StructViewer<T> {
     }
 }
 
+/// Text put on the clipboard by "Copy value": the interpreted value if the
+/// field has one, otherwise its raw bytes as hex
+fn field_value_text(bytes: &[u8], field: &FieldMeta, interpret: Option<&str>) -> String {
+    if let Some(interp) = interpret {
+        return interp.to_string();
+    }
+    if field.offset >= bytes.len() {
+        return String::new();
+    }
+    let end = (field.offset + field.size).min(bytes.len());
+    bytes[field.offset..end]
+        .iter()
+        .map(|b| format!("{:02X}", b))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Byte range of the first case-insensitive match of `query` in `text`.
+/// An empty query matches at the start.
+fn find_ignore_case(text: &str, query: &str) -> Option<(usize, usize)> {
+    if query.is_empty() {
+        return Some((0, 0));
+    }
+    let lower = text.to_lowercase();
+    let needle = query.to_lowercase();
+    // Lowercasing can change byte lengths outside ASCII; only trust
+    // positions when it didn't.
+    if lower.len() != text.len() {
+        return lower.contains(&needle).then_some((0, 0));
+    }
+    lower.find(&needle).map(|start| (start, start + needle.len()))
+}
+
+/// Draw `text` on one line with the part matching `query` in `color`
+fn text_with_highlight(ui: &Ui, text: &str, query: &str, color: [f32; 4]) {
+    match find_ignore_case(text, query) {
+        Some((start, end)) if end > start => {
+            if start > 0 {
+                ui.text(&text[..start]);
+                ui.same_line_with_spacing(0.0, 0.0);
+            }
+            ui.text_colored(color, &text[start..end]);
+            if end < text.len() {
+                ui.same_line_with_spacing(0.0, 0.0);
+                ui.text(&text[end..]);
+            }
+        }
+        _ => ui.text(text),
+    }
+}
+
 fn format_byte(byte: u8, mode: ByteDisplayMode) -> String {
     match mode {
         ByteDisplayMode::Hex => format!("{:02X}", byte),
@@ -664,3 +880,59 @@ fn format_byte(byte: u8, mode: ByteDisplayMode) -> String {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meta(name: &str, type_name: &'static str) -> FieldMeta {
+        FieldMeta {
+            name: name.to_string(),
+            offset: 0,
+            size: 4,
+            type_name,
+            interpret: Some("derived".to_string()),
+        }
+    }
+
+    #[test]
+    fn field_formatter_beats_type_formatter() {
+        let mut registry = FieldFormatterRegistry::new();
+        registry.register_type("u32", |_| "by type".to_string());
+        registry.register_field("flags", |_| "by field".to_string());
+
+        assert_eq!(registry.format(&meta("flags", "u32")).as_deref(), Some("by field"));
+        assert_eq!(registry.format(&meta("count", "u32")).as_deref(), Some("by type"));
+        assert_eq!(registry.format(&meta("speed", "f32")), None);
+    }
+
+    #[test]
+    fn array_elements_use_parent_and_element_type() {
+        let mut registry = FieldFormatterRegistry::new();
+        registry.register_field("pos", |f| format!("axis {}", f.name));
+        registry.register_type("i16", |_| "short".to_string());
+
+        assert_eq!(registry.format(&meta("pos.1", "f32")).as_deref(), Some("axis pos.1"));
+        assert_eq!(registry.format(&meta("ids.0", "i16")).as_deref(), Some("short"));
+        // Only numeric suffixes count as array elements
+        assert_eq!(registry.format(&meta("pos.x", "f32")), None);
+    }
+
+    #[test]
+    fn viewer_falls_back_to_derived_interpret() {
+        let mut registry = FieldFormatterRegistry::new();
+        registry.register_field("hp", |_| "custom".to_string());
+        let viewer = StructViewer::<DummyInfo>::new().with_formatters(Arc::new(registry));
+
+        assert_eq!(viewer.interpret_field(&meta("hp", "u32")).as_deref(), Some("custom"));
+        assert_eq!(viewer.interpret_field(&meta("mp", "u32")).as_deref(), Some("derived"));
+    }
+
+    struct DummyInfo;
+
+    impl FieldInfo for DummyInfo {
+        fn get_field_info(&self) -> Vec<FieldMeta> {
+            vec![]
+        }
+    }
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "gui")]
+
+use std::os::raw::c_char;
+
+use hackers::{DeriveFieldInfo, FieldInfo};
+
+#[repr(C)]
+#[derive(DeriveFieldInfo)]
+struct Player {
+    color: [f32; 4],
+    name: [c_char; 8],
+    hp: u32,
+}
+
+fn player() -> Player {
+    let mut name = [0 as c_char; 8];
+    for (dst, src) in name.iter_mut().zip(b"bob") {
+        *dst = *src as c_char;
+    }
+    Player { color: [0.25, 0.5, 0.75, 1.0], name, hp: 7 }
+}
+
+#[test]
+fn f32_array_expands_per_element() {
+    let fields = player().get_field_info();
+    let color: Vec<_> = fields.iter().filter(|f| f.name.starts_with("color.")).collect();
+
+    assert_eq!(color.len(), 4);
+    for (i, field) in color.iter().enumerate() {
+        assert_eq!(field.name, format!("color.{}", i));
+        assert_eq!(field.offset, i * 4);
+        assert_eq!(field.size, 4);
+        assert_eq!(field.type_name, "f32");
+    }
+    assert_eq!(color[1].interpret.as_deref(), Some("0.5"));
+}
+
+#[test]
+fn c_char_array_stays_one_string_field() {
+    let fields = player().get_field_info();
+    let names: Vec<_> = fields.iter().filter(|f| f.name.starts_with("name")).collect();
+
+    assert_eq!(names.len(), 1);
+    assert_eq!(names[0].name, "name");
+    assert_eq!(names[0].size, 8);
+    assert_eq!(names[0].interpret.as_deref(), Some("bob"));
+}
+
+#[test]
+fn scalar_fields_follow_arrays() {
+    let fields = player().get_field_info();
+    let hp = fields.iter().find(|f| f.name == "hp").unwrap();
+    assert_eq!(hp.offset, 24);
+    assert_eq!(hp.interpret.as_deref(), Some("7"));
+}